@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+
+use crate::FormalContext;
+
+// An object has a down arrow to an attribute it lacks if every object with a strictly larger intent has it
+pub fn down_arrows<T>(context: &FormalContext<T>) -> HashSet<(usize, usize)> {
+    let mut arrows = HashSet::new();
+    for (g, intent) in context.atomic_object_derivations.iter().enumerate() {
+        let larger: Vec<usize> = (0..context.objects.len())
+            .filter(|&h| {
+                intent.is_subset(&context.atomic_object_derivations[h])
+                    && *intent != context.atomic_object_derivations[h]
+            })
+            .collect();
+        for m in (0..context.attributes.len()).filter(|m| !intent.contains(*m)) {
+            if larger
+                .iter()
+                .all(|&h| context.atomic_object_derivations[h].contains(m))
+            {
+                arrows.insert((g, m));
+            }
+        }
+    }
+    arrows
+}
+
+// An object has an up arrow to an attribute it lacks if it has every attribute with a strictly larger extent
+pub fn up_arrows<T>(context: &FormalContext<T>) -> HashSet<(usize, usize)> {
+    let mut arrows = HashSet::new();
+    for (m, extent) in context.atomic_attribute_derivations.iter().enumerate() {
+        let larger: Vec<usize> = (0..context.attributes.len())
+            .filter(|&n| {
+                extent.is_subset(&context.atomic_attribute_derivations[n])
+                    && *extent != context.atomic_attribute_derivations[n]
+            })
+            .collect();
+        for g in (0..context.objects.len()).filter(|g| !extent.contains(*g)) {
+            if larger
+                .iter()
+                .all(|&n| context.atomic_attribute_derivations[n].contains(g))
+            {
+                arrows.insert((g, m));
+            }
+        }
+    }
+    arrows
+}
+
+// The double arrows are the pairs of irreducible objects and attributes which remain in the reduced context
+pub fn double_arrows<T>(context: &FormalContext<T>) -> HashSet<(usize, usize)> {
+    let up_arrows = up_arrows(context);
+    down_arrows(context)
+        .into_iter()
+        .filter(|pair| up_arrows.contains(pair))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs};
+
+    use crate::{
+        algorithms::arrow_relations::{double_arrows, down_arrows, up_arrows},
+        FormalContext,
+    };
+
+    #[test]
+    fn arrow_relations() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        let down: HashSet<(usize, usize)> = [
+            (0, 0),
+            (0, 2),
+            (0, 4),
+            (1, 0),
+            (1, 2),
+            (1, 3),
+            (2, 1),
+            (3, 3),
+            (3, 4),
+            (4, 1),
+            (5, 0),
+            (6, 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(down_arrows(&context), down);
+
+        let up = up_arrows(&context);
+        assert_eq!(up.len(), 18);
+        assert!(up.iter().all(|pair| !context.incidence.contains(pair)));
+
+        let double: HashSet<(usize, usize)> = [
+            (0, 2),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (2, 1),
+            (3, 3),
+            (3, 4),
+            (4, 1),
+            (5, 0),
+            (6, 1),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(double_arrows(&context), double);
+    }
+}
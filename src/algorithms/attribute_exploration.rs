@@ -1,11 +1,22 @@
 use bit_set::BitSet;
-use std::io::{self, Write};
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write};
 
-use crate::FormalContext;
+use crate::{FormalContext, FormatError};
 
 use super::canonical_basis;
 
-fn first_question(context: &FormalContext<String>, question: (&BitSet, &BitSet)) -> bool {
+// Clears the terminal, unless plain output without escape sequences is requested
+fn clear_screen<W: Write>(output: &mut W, plain: bool) {
+    if !plain {
+        write!(output, "{esc}[2J{esc}[1;1H", esc = 27 as char).unwrap();
+    }
+}
+
+fn first_question(
+    context: &FormalContext<String>,
+    question: (&BitSet, &BitSet),
+    plain: bool,
+) -> bool {
     let mut premise: Vec<String> = Vec::new();
     for index in question.0 {
         premise.push(context.attributes[index].to_string());
@@ -15,7 +26,7 @@ fn first_question(context: &FormalContext<String>, question: (&BitSet, &BitSet))
         conclusion.push(context.attributes[index].to_string());
     }
 
-    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+    clear_screen(&mut io::stdout(), plain);
     loop {
         let mut answer = String::new();
 
@@ -23,12 +34,12 @@ fn first_question(context: &FormalContext<String>, question: (&BitSet, &BitSet))
         println!("  {:?} -> {:?}", premise, conclusion);
 
         io::stdout()
-            .write(b"Please enter your answer (\"yes\", \"no\"): ")
+            .write_all(b"Please enter your answer (\"yes\", \"no\"): ")
             .unwrap();
         io::stdout().flush().unwrap();
         io::stdin().read_line(&mut answer).unwrap();
 
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+        clear_screen(&mut io::stdout(), plain);
 
         match answer.trim() {
             "yes" => return true,
@@ -40,8 +51,8 @@ fn first_question(context: &FormalContext<String>, question: (&BitSet, &BitSet))
     }
 }
 
-fn second_question(context: &FormalContext<String>) -> (String, BitSet) {
-    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+fn second_question(context: &FormalContext<String>, plain: bool) -> (String, BitSet) {
+    clear_screen(&mut io::stdout(), plain);
 
     let mut object = String::new();
     let mut attributes = String::new();
@@ -49,11 +60,13 @@ fn second_question(context: &FormalContext<String>) -> (String, BitSet) {
 
     loop {
         object.clear();
-        io::stdout().write(b"Enter name of new object: ").unwrap();
+        io::stdout()
+            .write_all(b"Enter name of new object: ")
+            .unwrap();
         io::stdout().flush().unwrap();
         io::stdin().read_line(&mut object).unwrap();
 
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+        clear_screen(&mut io::stdout(), plain);
 
         if object.is_ascii() {
             break;
@@ -62,64 +75,301 @@ fn second_question(context: &FormalContext<String>) -> (String, BitSet) {
         }
     }
 
-    'a: loop {
+    loop {
         attributes.clear();
-        io::stdout().write(
+        io::stdout().write_all(
             b"Name all attributes this object posesses.\nPlease use the following format: \"1. attr, 2. attr, 3. attr, ...\"\n"
         ).unwrap();
         io::stdout().flush().unwrap();
         io::stdin().read_line(&mut attributes).unwrap();
 
-        print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
+        clear_screen(&mut io::stdout(), plain);
 
         let names: Vec<&str> = attributes
             .trim()
             .split(",")
             .filter_map(|x| {
-                if x == "" {
+                if x.is_empty() {
                     return None;
                 }
                 Some(x.trim())
             })
             .collect();
 
+        attributes_set.clear();
+        let mut valid_names = true;
         for name in names {
-            let valid_name = match context.attributes.iter().position(|r| r.as_str() == name) {
-                Some(index) => attributes_set.insert(index),
-                None => false,
-            };
-            if !valid_name {
-                println!("Please only enter valid attribute names.\n");
-                break;
-            } else {
-                break 'a;
+            match context.attributes.iter().position(|r| r.as_str() == name) {
+                Some(index) => {
+                    attributes_set.insert(index);
+                }
+                None => {
+                    valid_names = false;
+                    break;
+                }
             }
         }
+        if valid_names {
+            break;
+        } else {
+            println!("Please only enter valid attribute names.\n");
+        }
     }
     object = object.trim().to_string();
 
     (object, attributes_set)
 }
 
+// Escape sequences are only written when stdout is a terminal
 pub fn attribute_exploration(context: &mut FormalContext<String>) -> Vec<(BitSet, BitSet)> {
-    let mut basis: Vec<(BitSet, BitSet)> = Vec::new();
-    let mut temp_set = BitSet::new();
-
-    while temp_set != (0..context.attributes.len()).collect() {
-        let temp_set_hull = context.index_attribute_hull(&temp_set);
-        while temp_set != temp_set_hull {
-            if first_question(
-                &context,
-                (&temp_set, &temp_set_hull.difference(&temp_set).collect()),
-            ) {
-                basis.push((temp_set.clone(), temp_set_hull));
+    run_exploration(context, !io::stdout().is_terminal())
+}
+
+// Never writes escape sequences, for logs and other output which is not shown in a terminal
+pub fn attribute_exploration_plain(context: &mut FormalContext<String>) -> Vec<(BitSet, BitSet)> {
+    run_exploration(context, true)
+}
+
+fn run_exploration(context: &mut FormalContext<String>, plain: bool) -> Vec<(BitSet, BitSet)> {
+    let mut state = ExplorationState::new(std::mem::take(context));
+
+    while let Some((premise, conclusion)) = state.next_question() {
+        if first_question(state.context(), (&premise, &conclusion), plain) {
+            state.confirm();
+        } else {
+            let (new_object, attributes) = second_question(state.context(), plain);
+            state.add_counterexample(new_object, &attributes);
+        }
+    }
+
+    *context = state.context;
+    state.basis
+}
+
+/// The state of an attribute exploration, which can be saved to stop a session and be loaded to resume it later.
+///
+/// The crate does not depend on serde, so the state is saved in a plain text format instead. The first line holds the
+/// current premise as attribute indices separated by spaces, the second line the number of confirmed implications and
+/// each following line one implication as the indices of its premise and conclusion separated by `;`. The context
+/// with all counterexamples follows in Burmeister format, its names are read back unchanged as long as they contain
+/// no line breaks.
+pub struct ExplorationState {
+    context: FormalContext<String>,
+    temp_set: BitSet,
+    basis: Vec<(BitSet, BitSet)>,
+}
+
+impl ExplorationState {
+    /// Starts an exploration of the given context.
+    pub fn new(context: FormalContext<String>) -> Self {
+        ExplorationState {
+            context,
+            temp_set: BitSet::new(),
+            basis: Vec::new(),
+        }
+    }
+
+    /// Returns the context including all counterexamples added so far.
+    pub fn context(&self) -> &FormalContext<String> {
+        &self.context
+    }
+
+    /// Returns the implications confirmed so far.
+    pub fn basis(&self) -> &[(BitSet, BitSet)] {
+        &self.basis
+    }
+
+    /// Returns the premise and conclusion of the next implication to ask about, or None if the exploration is finished.
+    pub fn next_question(&mut self) -> Option<(BitSet, BitSet)> {
+        while self.temp_set != *self.context.all_attributes() {
+            let hull = self.context.index_attribute_hull(&self.temp_set);
+            if hull != self.temp_set {
+                return Some((
+                    self.temp_set.clone(),
+                    hull.difference(&self.temp_set).collect(),
+                ));
+            }
+            self.advance();
+        }
+        None
+    }
+
+    /// Accepts the current implication and moves on to the next premise.
+    pub fn confirm(&mut self) {
+        let hull = self.context.index_attribute_hull(&self.temp_set);
+        self.basis.push((self.temp_set.clone(), hull));
+        self.advance();
+    }
+
+    /// Rejects the current implication by adding an object which violates it.
+    pub fn add_counterexample(&mut self, object: String, attributes: &BitSet) {
+        self.context.add_object(object, attributes);
+    }
+
+    fn advance(&mut self) {
+        self.temp_set =
+            canonical_basis::next_preclosure(&self.context, &self.basis, &self.temp_set);
+    }
+
+    /// Writes the current premise and the confirmed implications, followed by the context in Burmeister format.
+    pub fn save<W: Write>(&self, writer: &mut W) -> Result<(), io::Error> {
+        writeln!(writer, "{}", write_indices(&self.temp_set))?;
+        writeln!(writer, "{}", self.basis.len())?;
+        for (premise, conclusion) in &self.basis {
+            writeln!(
+                writer,
+                "{};{}",
+                write_indices(premise),
+                write_indices(conclusion)
+            )?;
+        }
+        self.context.write_burmeister(writer)
+    }
+
+    /// Reads a state written by save.
+    pub fn load<R: Read>(reader: R) -> Result<Self, FormatError> {
+        let mut reader = BufReader::new(reader);
+        let mut line = String::new();
+
+        reader.read_line(&mut line)?;
+        let temp_set = read_indices(&line)?;
+
+        line.clear();
+        reader.read_line(&mut line)?;
+        let basis_length: usize = line.trim_end().parse()?;
+
+        let mut basis = Vec::with_capacity(basis_length);
+        for _ in 0..basis_length {
+            line.clear();
+            reader.read_line(&mut line)?;
+            let (premise, conclusion) = line
+                .trim_end()
+                .split_once(';')
+                .ok_or(FormatError::InvalidFormat)?;
+            basis.push((read_indices(premise)?, read_indices(conclusion)?));
+        }
+
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents)?;
+        let context = FormalContext::<String>::from(&contents)?;
+
+        Ok(ExplorationState {
+            context,
+            temp_set,
+            basis,
+        })
+    }
+}
+
+fn write_indices(set: &BitSet) -> String {
+    set.iter()
+        .map(|index| index.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn read_indices(line: &str) -> Result<BitSet, FormatError> {
+    let mut set = BitSet::new();
+    for index in line.split_whitespace() {
+        set.insert(index.parse()?);
+    }
+    Ok(set)
+}
+
+#[cfg(test)]
+mod tests {
+    use bit_set::BitSet;
+    use std::fs;
+
+    use crate::{
+        algorithms::attribute_exploration::{clear_screen, ExplorationState},
+        FormalContext,
+    };
+
+    // Answers the questions with the full context as the expert and returns the questions asked
+    fn explore(
+        state: &mut ExplorationState,
+        expert: &FormalContext<String>,
+        questions: usize,
+    ) -> Vec<(BitSet, BitSet)> {
+        let mut asked = Vec::new();
+        while asked.len() < questions {
+            let Some((premise, conclusion)) = state.next_question() else {
                 break;
-            } else {
-                let (new_object, attributes) = second_question(&context);
-                context.add_object(new_object, &attributes);
+            };
+            match (0..expert.objects.len()).find(|&g| {
+                premise.is_subset(&expert.atomic_object_derivations[g])
+                    && !conclusion.is_subset(&expert.atomic_object_derivations[g])
+            }) {
+                Some(g) => state.add_counterexample(
+                    expert.objects[g].clone(),
+                    &expert.atomic_object_derivations[g],
+                ),
+                None => state.confirm(),
             }
+            asked.push((premise, conclusion));
         }
-        temp_set = canonical_basis::next_preclosure(context, &basis, &temp_set)
+        asked
+    }
+
+    #[test]
+    fn test_clear_screen() {
+        let mut output: Vec<u8> = Vec::new();
+        clear_screen(&mut output, false);
+        assert!(output.contains(&27));
+
+        let mut output: Vec<u8> = Vec::new();
+        clear_screen(&mut output, true);
+        assert!(!output.contains(&27));
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let expert = FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let mut start = expert.clone();
+        for g in (2..expert.objects.len()).rev() {
+            start.remove_object(g);
+        }
+
+        let mut state = ExplorationState::new(start.clone());
+        let first = explore(&mut state, &expert, 5);
+        assert_eq!(first.len(), 5);
+
+        let mut saved = Vec::new();
+        state.save(&mut saved).unwrap();
+        let mut loaded = ExplorationState::load(saved.as_slice()).unwrap();
+        assert_eq!(loaded.context().objects, state.context().objects);
+        assert_eq!(loaded.context().attributes, state.context().attributes);
+
+        let rest = explore(&mut state, &expert, usize::MAX);
+        let loaded_rest = explore(&mut loaded, &expert, usize::MAX);
+        assert!(!rest.is_empty());
+        assert_eq!(rest, loaded_rest);
+        assert_eq!(state.basis(), loaded.basis());
+        assert_eq!(state.basis(), expert.canonical_basis());
+
+        let mut uninterrupted = ExplorationState::new(start);
+        let all = explore(&mut uninterrupted, &expert, usize::MAX);
+        assert_eq!(all, [first, rest].concat());
+    }
+
+    #[test]
+    fn test_save_and_load_keeps_names() {
+        let context = FormalContext::<String>::from(b"B\n\n1\n2\n\n g\na \nb\nX.\n").unwrap();
+        let mut state = ExplorationState::new(context);
+        state.add_counterexample("h  ".to_string(), &BitSet::new());
+
+        let mut saved = Vec::new();
+        state.save(&mut saved).unwrap();
+        let loaded = ExplorationState::load(saved.as_slice()).unwrap();
+
+        assert_eq!(
+            loaded.context().objects,
+            vec![" g".to_string(), "h  ".to_string()]
+        );
+        assert_eq!(
+            loaded.context().attributes,
+            vec!["a ".to_string(), "b".to_string()]
+        );
     }
-    basis
 }
@@ -0,0 +1,82 @@
+use bit_set::BitSet;
+use std::collections::HashSet;
+
+use crate::FormalContext;
+
+// A block relation contains the incidence and all its rows are intents and all its columns are extents of the context
+// The concept lattice of the resulting context is the factor lattice by the corresponding complete tolerance relation
+pub fn block_relation<T: Clone>(
+    context: &FormalContext<T>,
+    incidence: &HashSet<(usize, usize)>,
+) -> FormalContext<T> {
+    let mut rows = context.atomic_object_derivations.clone();
+    for &(g, m) in incidence {
+        rows[g].insert(m);
+    }
+
+    // Closes rows and columns alternately until both are stable, which yields the smallest block relation
+    loop {
+        let mut changed = false;
+
+        for row in rows.iter_mut() {
+            let hull = context.index_attribute_hull(row);
+            if hull != *row {
+                *row = hull;
+                changed = true;
+            }
+        }
+
+        for m in 0..context.attributes.len() {
+            let column: BitSet = (0..context.objects.len())
+                .filter(|&g| rows[g].contains(m))
+                .collect();
+            for g in context.index_object_hull(&column).difference(&column) {
+                rows[g].insert(m);
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    let incidence = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(g, row)| row.iter().map(move |m| (g, m)))
+        .collect();
+
+    FormalContext::construct(
+        context.objects.clone(),
+        context.attributes.clone(),
+        incidence,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs};
+
+    use crate::{algorithms::block_relation::block_relation, FormalContext};
+
+    #[test]
+    fn test_block_relation() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        let factor = block_relation(&context, &HashSet::new());
+        assert_eq!(factor.incidence, context.incidence);
+
+        let factor = block_relation(&context, &HashSet::from([(2, 0)]));
+        assert!(factor.incidence.is_superset(&context.incidence));
+        assert!(factor.incidence.contains(&(2, 0)));
+        for row in &factor.atomic_object_derivations {
+            assert_eq!(context.index_attribute_hull(row), *row);
+        }
+        for column in &factor.atomic_attribute_derivations {
+            assert_eq!(context.index_object_hull(column), *column);
+        }
+        assert!(factor.fcbo_index_concepts().count() <= context.fcbo_index_concepts().count());
+    }
+}
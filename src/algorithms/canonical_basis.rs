@@ -1,7 +1,8 @@
 use bit_set::BitSet;
-use std::collections::HashMap;
+use std::collections::HashSet;
 
-use crate::FormalContext;
+use super::proper_premises::proper_premises;
+use crate::{ContextKind, FormalContext};
 
 fn is_smallest_num(min: usize, input_set: &BitSet) -> bool {
     for n in 0..min {
@@ -12,21 +13,13 @@ fn is_smallest_num(min: usize, input_set: &BitSet) -> bool {
     true
 }
 
-fn set_upto(n: usize) -> BitSet {
-    let mut b = BitSet::new();
-    for i in 0..n + 1 {
-        b.insert(i);
-    }
-    b
-}
-
 fn retain_eq_less(max: usize, input_set: &BitSet) -> BitSet {
     let output = input_set.iter().filter(|x| x <= &max).collect();
     output
 }
 
-fn implication_closure(implications: &Vec<(BitSet, BitSet)>, input: &BitSet) -> BitSet {
-    let mut implications = implications.clone();
+pub(crate) fn implication_closure(implications: &[(BitSet, BitSet)], input: &BitSet) -> BitSet {
+    let mut implications = implications.to_vec();
     let mut output = input.clone();
 
     loop {
@@ -34,7 +27,7 @@ fn implication_closure(implications: &Vec<(BitSet, BitSet)>, input: &BitSet) ->
         let mut repeat = false;
         for (index, (premise, conclusion)) in implications.iter().enumerate() {
             if premise.is_subset(&output) {
-                output.union_with(&conclusion);
+                output.union_with(conclusion);
                 indices.insert(index);
                 repeat = true;
             }
@@ -56,82 +49,231 @@ fn implication_closure(implications: &Vec<(BitSet, BitSet)>, input: &BitSet) ->
     output
 }
 
-fn implication_closure_lin(implications: &Vec<(BitSet, BitSet)>, input: &BitSet) -> BitSet {
-    let mut output = input.clone();
+// Checks if the implication follows from the given implications
+pub fn entails(implications: &[(BitSet, BitSet)], implication: (&BitSet, &BitSet)) -> bool {
+    implication
+        .1
+        .is_subset(&implication_closure(implications, implication.0))
+}
 
-    let mut count: HashMap<(&BitSet, &BitSet), usize> = HashMap::new();
-    let mut list: HashMap<usize, Vec<(&BitSet, &BitSet)>> = HashMap::new();
+// Checks if both sets of implications have the same closure operator, i.e. each entails every implication of the other
+pub fn implications_equivalent(a: &[(BitSet, BitSet)], b: &[(BitSet, BitSet)]) -> bool {
+    a.iter()
+        .all(|(premise, conclusion)| entails(b, (premise, conclusion)))
+        && b.iter()
+            .all(|(premise, conclusion)| entails(a, (premise, conclusion)))
+}
 
-    for (premise, conclusion) in implications {
-        count.insert((premise, conclusion), premise.len());
-        if premise.len() == 0 {
-            output.union_with(conclusion);
+// Computes an equivalent set of implications of minimum size, whose premises and conclusions can not be made
+// smaller, and whose conclusions do not repeat the premise
+// Every conclusion is first replaced by the closure of its premise and the redundant implications are removed.
+// An implication whose premise directly determines another premise with the same closure is then redundant, so
+// Maier's condition for a minimum cover holds without merging, and reducing the sides keeps the size
+// The result has as many implications as the canonical basis, but unlike there the premises do not have to be
+// pseudo-closed and the conclusions are not closed
+pub fn minimum_cover(implications: &[(BitSet, BitSet)]) -> Vec<(BitSet, BitSet)> {
+    let mut cover: Vec<(BitSet, BitSet)> = implications
+        .iter()
+        .map(|(premise, _)| (premise.clone(), implication_closure(implications, premise)))
+        .collect();
+
+    let mut index = 0;
+    while index < cover.len() {
+        let (premise, conclusion) = cover.remove(index);
+        if !entails(&cover, (&premise, &conclusion)) {
+            cover.insert(index, (premise, conclusion));
+            index += 1;
         }
-        for a in premise {
-            if list.contains_key(&a) {
-                list.get_mut(&a).unwrap().push((premise, conclusion));
-            } else {
-                list.insert(a, vec![(premise, conclusion)]);
+    }
+
+    for index in 0..cover.len() {
+        let (mut premise, conclusion) = cover[index].clone();
+        for m in &cover[index].0 {
+            let mut smaller = premise.clone();
+            smaller.remove(m);
+            if conclusion.is_subset(&implication_closure(&cover, &smaller)) {
+                premise = smaller;
             }
         }
+        cover[index].0 = premise;
     }
 
-    let mut update = output.clone();
-    let empty_set = BitSet::new();
-
-    while update != empty_set {
-        let m = update.iter().next().unwrap();
-        update.remove(m);
-
-        if list.contains_key(&m) {
-            for entry in list.get(&m).unwrap() {
-                *count.get_mut(entry).unwrap() -= 1;
-                if *count.get(entry).unwrap() == 0 {
-                    let add = entry.1.difference(&output).collect();
-                    output.union_with(&add);
-                    update.union_with(&add);
-                }
+    for index in 0..cover.len() {
+        let (premise, closure) = cover[index].clone();
+        let mut conclusion: BitSet = closure.difference(&premise).collect();
+        for m in &closure {
+            if !conclusion.contains(m) {
+                continue;
+            }
+            conclusion.remove(m);
+            cover[index].1 = conclusion.clone();
+            if !closure.is_subset(&implication_closure(&cover, &premise)) {
+                conclusion.insert(m);
             }
         }
+        cover[index].1 = conclusion;
     }
-    output
+
+    cover.retain(|(_, conclusion)| !conclusion.is_empty());
+    cover
 }
 
 pub fn next_preclosure<T>(
     context: &FormalContext<T>,
-    implications: &Vec<(BitSet, BitSet)>,
+    implications: &[(BitSet, BitSet)],
     input: &BitSet,
+) -> BitSet {
+    next_implication_closed_set(context.attributes.len(), implications, input)
+}
+
+// Computes the lectically next set closed under the implications, or all attributes if there is none
+pub(crate) fn next_implication_closed_set(
+    attribute_count: usize,
+    implications: &[(BitSet, BitSet)],
+    input: &BitSet,
+) -> BitSet {
+    next_closed_set(attribute_count, input, &|set| {
+        implication_closure(implications, set)
+    })
+}
+
+// Computes the lectically next set closed under the given closure, or all attributes if there is none
+fn next_closed_set(
+    attribute_count: usize,
+    input: &BitSet,
+    closure: &dyn Fn(&BitSet) -> BitSet,
 ) -> BitSet {
     let mut temp_set = input.clone();
 
-    for m in (0..context.attributes.len()).rev() {
+    for m in (0..attribute_count).rev() {
         if temp_set.contains(m) {
             temp_set.remove(m);
         } else {
             temp_set.insert(m);
-            let output = implication_closure(implications, &temp_set);
+            let output = closure(&temp_set);
             if is_smallest_num(m, &output.difference(&temp_set).collect()) {
                 return output;
             }
             temp_set.remove(m);
         }
     }
-    return (0..context.attributes.len()).collect();
+    (0..attribute_count).collect()
 }
 
 pub fn canonical_basis<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
+    canonical_basis_with_progress(context, &mut |_| {})
+}
+
+// Reports the number of implications found so far every time a new implication is found
+pub fn canonical_basis_with_progress<T>(
+    context: &FormalContext<T>,
+    progress: &mut dyn FnMut(usize),
+) -> Vec<(BitSet, BitSet)> {
+    canonical_basis_walk(context, progress, &|| false)
+}
+
+// Stops as soon as should_cancel returns true and returns the implications found so far
+pub fn canonical_basis_cancellable<T>(
+    context: &FormalContext<T>,
+    should_cancel: &dyn Fn() -> bool,
+) -> Vec<(BitSet, BitSet)> {
+    canonical_basis_walk(context, &mut |_| {}, should_cancel)
+}
+
+fn canonical_basis_walk<T>(
+    context: &FormalContext<T>,
+    progress: &mut dyn FnMut(usize),
+    should_cancel: &dyn Fn() -> bool,
+) -> Vec<(BitSet, BitSet)> {
+    // Without objects or with every incidence all attributes follow from the empty set
+    if matches!(context.kind(), ContextKind::Empty | ContextKind::Full) {
+        let hull = context.index_attribute_hull(&BitSet::new());
+        if hull.is_empty() {
+            return Vec::new();
+        }
+        progress(1);
+        return vec![(BitSet::new(), hull)];
+    }
+
     let mut temp_set = BitSet::new();
     let mut implications: Vec<(BitSet, BitSet)> = Vec::new();
-    while temp_set != set_upto(context.attributes.len() - 1) {
+    while temp_set != *context.all_attributes() {
+        if should_cancel() {
+            break;
+        }
         let temp_set_hull = context.index_attribute_hull(&temp_set);
         if temp_set != temp_set_hull {
             implications.push((temp_set.clone(), temp_set_hull));
+            progress(implications.len());
         }
-        temp_set = next_preclosure(&context, &implications, &temp_set);
+        temp_set = next_preclosure(context, &implications, &temp_set);
     }
     implications
 }
 
+// The canonical basis of the attribute zoom on the scope, given in the attribute indices of the context
+// This is computed from the closure restricted to the scope, not by filtering the canonical basis of the context
+pub fn local_basis<T>(context: &FormalContext<T>, scope: &BitSet) -> Vec<(BitSet, BitSet)> {
+    let kept: Vec<usize> = scope.iter().collect();
+    let mut incidence = HashSet::new();
+    for (g, intent) in context.atomic_object_derivations.iter().enumerate() {
+        for (m, &old_m) in kept.iter().enumerate() {
+            if intent.contains(old_m) {
+                incidence.insert((g, m));
+            }
+        }
+    }
+    let zoomed = FormalContext::construct(
+        (0..context.objects.len()).collect(),
+        kept.clone(),
+        incidence,
+    );
+
+    let to_scope = |set: &BitSet| -> BitSet { set.iter().map(|m| kept[m]).collect() };
+    canonical_basis(&zoomed)
+        .iter()
+        .map(|(premise, conclusion)| (to_scope(premise), to_scope(conclusion)))
+        .collect()
+}
+
+// Counts the pseudo-intents of the context
+// Only the pseudo-intents found so far are kept, the conclusion of each is its hull and is recomputed
+// whenever the preclosure needs it, so no implications are stored
+pub fn canonical_basis_size<T>(context: &FormalContext<T>) -> usize {
+    let mut premises: Vec<BitSet> = Vec::new();
+    let mut temp_set = BitSet::new();
+    while temp_set != *context.all_attributes() {
+        if context.index_attribute_hull(&temp_set) != temp_set {
+            premises.push(temp_set.clone());
+        }
+        temp_set = next_closed_set(context.attributes.len(), &temp_set, &|set| {
+            premise_closure(context, &premises, set)
+        });
+    }
+    premises.len()
+}
+
+// Closes the set under the implications from each premise to its hull
+fn premise_closure<T>(context: &FormalContext<T>, premises: &[BitSet], input: &BitSet) -> BitSet {
+    let mut output = input.clone();
+    let mut applied = BitSet::new();
+
+    loop {
+        let mut repeat = false;
+        for (index, premise) in premises.iter().enumerate() {
+            if !applied.contains(index) && premise.is_subset(&output) {
+                output.union_with(&context.index_attribute_hull(premise));
+                applied.insert(index);
+                repeat = true;
+            }
+        }
+        if !repeat {
+            break;
+        }
+    }
+    output
+}
+
 pub fn canonical_basis_optimised<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
     let mut temp_set = context.index_attribute_hull(&BitSet::new());
     let mut implications: Vec<(BitSet, BitSet)> = Vec::new();
@@ -140,9 +282,14 @@ pub fn canonical_basis_optimised<T>(context: &FormalContext<T>) -> Vec<(BitSet,
         implications.push((BitSet::new(), temp_set.clone()));
     }
 
+    // Without objects or attributes the hull of the empty set is already all attributes and there is nothing to walk
+    if context.kind() == ContextKind::Empty {
+        return implications;
+    }
+
     let mut i = context.attributes.len() - 1;
 
-    while temp_set != set_upto(context.attributes.len() - 1) {
+    while temp_set != *context.all_attributes() {
         for j in (0..i + 1).rev() {
             if temp_set.contains(j) {
                 temp_set.remove(j);
@@ -173,13 +320,126 @@ pub fn canonical_basis_optimised<T>(context: &FormalContext<T>) -> Vec<(BitSet,
     implications
 }
 
+// Finds the pseudo-intents level by level, a set is a pseudo-intent exactly when it is not an intent
+// and respects the implications of all smaller pseudo-intents, so the premise sizes can be cut off
+// The result may not be complete, it only contains the implications of the canonical basis up to max_premise
+pub fn implications_up_to<T>(
+    context: &FormalContext<T>,
+    max_premise: usize,
+) -> Vec<(BitSet, BitSet)> {
+    let mut implications: Vec<(BitSet, BitSet)> = Vec::new();
+    let mut level: Vec<BitSet> = vec![BitSet::new()];
+
+    for size in 0..=max_premise.min(context.attributes.len()) {
+        for premise in &level {
+            if implication_closure(&implications, premise) != *premise {
+                continue;
+            }
+            let hull = context.index_attribute_hull(premise);
+            if hull != *premise {
+                implications.push((premise.clone(), hull));
+            }
+        }
+
+        if size < max_premise {
+            level = level
+                .iter()
+                .flat_map(|set| {
+                    let start = set.iter().last().map_or(0, |m| m + 1);
+                    (start..context.attributes.len()).map(move |m| {
+                        let mut next = set.clone();
+                        next.insert(m);
+                        next
+                    })
+                })
+                .collect();
+        }
+    }
+    implications
+}
+
+// The minimal premises of single attributes are the proper premises, which need not be pseudo-closed
+pub fn unary_implications<T>(context: &FormalContext<T>) -> Vec<(BitSet, usize)> {
+    let mut implications: Vec<(BitSet, usize)> = Vec::new();
+    for (premise, conclusion) in proper_premises(context) {
+        for m in &conclusion {
+            implications.push((premise.clone(), m));
+        }
+    }
+    implications
+}
+
+// Counts the sets closed under the implications by walking them in lectic order
+pub fn implication_closure_system_size(
+    implications: &[(BitSet, BitSet)],
+    attribute_count: usize,
+) -> usize {
+    let all_attributes: BitSet = (0..attribute_count).collect();
+
+    let mut count = 1;
+    let mut temp_set = implication_closure(implications, &BitSet::new());
+    while temp_set != all_attributes {
+        count += 1;
+        temp_set = next_implication_closed_set(attribute_count, implications, &temp_set);
+    }
+    count
+}
+
+// Constructs a context whose intents are exactly the sets closed under the implications
+// Only the meet-irreducible closed sets are used as objects, all other closed sets are intersections of them
+pub fn armstrong_context(
+    implications: &[(BitSet, BitSet)],
+    attribute_count: usize,
+) -> FormalContext<usize> {
+    let all_attributes: BitSet = (0..attribute_count).collect();
+
+    let mut closed_sets: Vec<BitSet> = Vec::new();
+    let mut temp_set = implication_closure(implications, &BitSet::new());
+    while temp_set != all_attributes {
+        closed_sets.push(temp_set.clone());
+        temp_set = next_implication_closed_set(attribute_count, implications, &temp_set);
+    }
+
+    let rows: Vec<&BitSet> = closed_sets
+        .iter()
+        .filter(|set| {
+            let mut intersection = all_attributes.clone();
+            for other in &closed_sets {
+                if set.is_subset(other) && set != &other {
+                    intersection.intersect_with(other);
+                }
+            }
+            intersection != **set
+        })
+        .collect();
+
+    let incidence = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(g, row)| row.iter().map(move |m| (g, m)))
+        .collect();
+
+    FormalContext::construct(
+        (0..rows.len()).collect(),
+        (0..attribute_count).collect(),
+        incidence,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::algorithms::{
-        canonical_basis::{canonical_basis, implication_closure, next_preclosure},
+        canonical_basis::{
+            armstrong_context, canonical_basis, canonical_basis_cancellable,
+            canonical_basis_optimised, canonical_basis_size, canonical_basis_with_progress,
+            entails, implication_closure, implication_closure_system_size, implications_equivalent,
+            implications_up_to, local_basis, minimum_cover, next_preclosure, unary_implications,
+        },
         FormalContext,
     };
+    use crate::util::bitset_from_indices;
     use bit_set::BitSet;
+    use itertools::Itertools;
     use std::fs;
 
     #[test]
@@ -189,31 +449,76 @@ mod tests {
 
         let output = canonical_basis(&context);
 
-        let mut canonical_basis = Vec::new();
-        // {3,4} -> {0,1,2,3,4}
-        canonical_basis.push((
-            BitSet::from_bytes(&[0b00011000]),
-            BitSet::from_bytes(&[0b11111000]),
-        ));
-        // {2,4} -> {0,1,2,3,4}
-        canonical_basis.push((
-            BitSet::from_bytes(&[0b00101000]),
-            BitSet::from_bytes(&[0b11111000]),
-        ));
-        // {2,3} -> {0,1,2,3,4}
-        canonical_basis.push((
-            BitSet::from_bytes(&[0b00110000]),
-            BitSet::from_bytes(&[0b11111000]),
-        ));
-        // {0} -> {0,1,2}
-        canonical_basis.push((
-            BitSet::from_bytes(&[0b10000000]),
-            BitSet::from_bytes(&[0b11100000]),
-        ));
+        let canonical_basis = vec![
+            // {3,4} -> {0,1,2,3,4}
+            (
+                bitset_from_indices(&[3, 4]),
+                bitset_from_indices(&[0, 1, 2, 3, 4]),
+            ),
+            // {2,4} -> {0,1,2,3,4}
+            (
+                bitset_from_indices(&[2, 4]),
+                bitset_from_indices(&[0, 1, 2, 3, 4]),
+            ),
+            // {2,3} -> {0,1,2,3,4}
+            (
+                bitset_from_indices(&[2, 3]),
+                bitset_from_indices(&[0, 1, 2, 3, 4]),
+            ),
+            // {0} -> {0,1,2}
+            (bitset_from_indices(&[0]), bitset_from_indices(&[0, 1, 2])),
+        ];
 
         assert_eq!(output, canonical_basis);
     }
 
+    #[test]
+    fn canonical_basis_degenerate() {
+        let all = bitset_from_indices(&[0, 1]);
+        let cases = [
+            (b"B\n\n2\n0\n\ng\nh\n\n\n".to_vec(), vec![]),
+            (
+                b"B\n\n0\n2\n\na\nb\n".to_vec(),
+                vec![(BitSet::new(), all.clone())],
+            ),
+            (
+                b"B\n\n2\n2\n\ng\nh\na\nb\nXX\nXX\n".to_vec(),
+                vec![(BitSet::new(), all.clone())],
+            ),
+            (
+                b"B\n\n2\n2\n\ng\nh\na\nb\n..\n..\n".to_vec(),
+                vec![
+                    (bitset_from_indices(&[1]), all.clone()),
+                    (bitset_from_indices(&[0]), all.clone()),
+                ],
+            ),
+        ];
+
+        for (contents, basis) in cases {
+            let context = FormalContext::<String>::from(&contents).unwrap();
+            assert_eq!(canonical_basis(&context), basis);
+            assert_eq!(canonical_basis_optimised(&context), basis);
+            assert_eq!(canonical_basis_size(&context), basis.len());
+        }
+    }
+
+    #[test]
+    fn canonical_basis_optimised_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert_eq!(
+            canonical_basis_optimised(&context),
+            canonical_basis(&context)
+        );
+
+        let mut context = FormalContext::<String>::new();
+        assert!(canonical_basis_optimised(&context).is_empty());
+
+        context.add_object("g".to_string(), &BitSet::new());
+        assert!(canonical_basis_optimised(&context).is_empty());
+    }
+
     #[test]
     fn next_closure_test() {
         let context =
@@ -223,56 +528,54 @@ mod tests {
 
         let input = BitSet::new();
         let output = next_preclosure(&context, &canonical_basis, &input);
-        assert_eq!(output, BitSet::from_bytes(&[0b00001000]));
+        assert_eq!(output, bitset_from_indices(&[4]));
 
-        let input = BitSet::from_bytes(&[0b00001000]);
+        let input = bitset_from_indices(&[4]);
         let output = next_preclosure(&context, &canonical_basis, &input);
-        assert_eq!(output, BitSet::from_bytes(&[0b00010000]));
+        assert_eq!(output, bitset_from_indices(&[3]));
 
-        let input = BitSet::from_bytes(&[0b00010000]);
+        let input = bitset_from_indices(&[3]);
         let output = next_preclosure(&context, &canonical_basis, &input);
-        assert_eq!(output, BitSet::from_bytes(&[0b00011000]));
+        assert_eq!(output, bitset_from_indices(&[3, 4]));
 
         // {3,4} -> {0,1,2,3,4}
         canonical_basis.push((
-            BitSet::from_bytes(&[0b00011000]),
-            BitSet::from_bytes(&[0b11111000]),
+            bitset_from_indices(&[3, 4]),
+            bitset_from_indices(&[0, 1, 2, 3, 4]),
         ));
-        let input = BitSet::from_bytes(&[0b00011000]);
+        let input = bitset_from_indices(&[3, 4]);
         let output = next_preclosure(&context, &canonical_basis, &input);
-        assert_eq!(output, BitSet::from_bytes(&[0b00100000]));
+        assert_eq!(output, bitset_from_indices(&[2]));
     }
 
     #[test]
     fn implication_closure_test() {
-        let mut implications = Vec::new();
-        // {1} -> {1,2,3}
-        implications.push((
-            BitSet::from_bytes(&[0b01000000]),
-            BitSet::from_bytes(&[0b00110000]),
-        ));
-        // {4,5} -> {1,2,3,4,5}
-        implications.push((
-            BitSet::from_bytes(&[0b00001100]),
-            BitSet::from_bytes(&[0b01111100]),
-        ));
-        // {3,5} -> {1,2,3,4,5}
-        implications.push((
-            BitSet::from_bytes(&[0b00010100]),
-            BitSet::from_bytes(&[0b01111100]),
-        ));
-        // {3,4} -> {1,2,3,4,5}
-        implications.push((
-            BitSet::from_bytes(&[0b00011000]),
-            BitSet::from_bytes(&[0b01111100]),
-        ));
+        let implications = vec![
+            // {1} -> {1,2,3}
+            (bitset_from_indices(&[1]), bitset_from_indices(&[2, 3])),
+            // {4,5} -> {1,2,3,4,5}
+            (
+                bitset_from_indices(&[4, 5]),
+                bitset_from_indices(&[1, 2, 3, 4, 5]),
+            ),
+            // {3,5} -> {1,2,3,4,5}
+            (
+                bitset_from_indices(&[3, 5]),
+                bitset_from_indices(&[1, 2, 3, 4, 5]),
+            ),
+            // {3,4} -> {1,2,3,4,5}
+            (
+                bitset_from_indices(&[3, 4]),
+                bitset_from_indices(&[1, 2, 3, 4, 5]),
+            ),
+        ];
 
         let mut input = BitSet::new();
         input.insert(1);
         // {1,2,3}
         assert_eq!(
             implication_closure(&implications, &input),
-            BitSet::from_bytes(&[0b01110000])
+            bitset_from_indices(&[1, 2, 3])
         );
 
         let mut input = BitSet::new();
@@ -281,7 +584,7 @@ mod tests {
         // {1,2,3,4,5}
         assert_eq!(
             implication_closure(&implications, &input),
-            BitSet::from_bytes(&[0b01111100])
+            bitset_from_indices(&[1, 2, 3, 4, 5])
         );
 
         let mut input = BitSet::new();
@@ -290,7 +593,7 @@ mod tests {
         // {1,2,3,4,5}
         assert_eq!(
             implication_closure(&implications, &input),
-            BitSet::from_bytes(&[0b01111100])
+            bitset_from_indices(&[1, 2, 3, 4, 5])
         );
 
         let mut input = BitSet::new();
@@ -299,7 +602,344 @@ mod tests {
         // {1,2,3,4,5}
         assert_eq!(
             implication_closure(&implications, &input),
-            BitSet::from_bytes(&[0b01111100])
+            bitset_from_indices(&[1, 2, 3, 4, 5])
+        );
+    }
+
+    #[test]
+    fn unary_implications_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        let output = unary_implications(&context);
+
+        assert!(!output.is_empty());
+        for (premise, m) in &output {
+            assert!(!premise.contains(*m));
+            let mut conclusion = BitSet::new();
+            conclusion.insert(*m);
+            assert!(context.implication_holds(premise, &conclusion));
+        }
+    }
+
+    #[test]
+    fn unary_implications_with_nonempty_closure_of_empty_set() {
+        // Every object has a, so the pseudo-intents all contain a while {b} -> c already holds
+        let context =
+            FormalContext::<String>::from(b"B\n\n2\n3\n\ng\nh\na\nb\nc\nXXX\nX..\n").unwrap();
+
+        let output = unary_implications(&context);
+
+        assert_eq!(
+            output,
+            vec![
+                (BitSet::new(), 0),
+                (BitSet::from_iter([2]), 1),
+                (BitSet::from_iter([1]), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn armstrong_context_fixtures() {
+        // One object for each meet-irreducible intent. The triangles context is reduced, so these are exactly its
+        // 7 object intents
+        let fixtures = [
+            ("test_data/triangles.cxt", 7),
+            ("test_data/living_beings_and_water.cxt", 8),
+        ];
+
+        for (path, meet_irreducibles) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let basis = canonical_basis(&context);
+
+            let armstrong = armstrong_context(&basis, context.attributes.len());
+
+            assert_eq!(armstrong.objects.len(), meet_irreducibles, "{path}");
+            assert_eq!(canonical_basis(&armstrong), basis, "{path}");
+        }
+    }
+
+    #[test]
+    fn canonical_basis_size_fixtures() {
+        let fixtures = [
+            // Pseudo-intents {0}, {2,3}, {2,4} and {3,4}
+            ("test_data/triangles.cxt", 4),
+            ("test_data/living_beings_and_water.cxt", 10),
+            ("test_data/eu.cxt", 9),
+        ];
+
+        for (path, pseudo_intents) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            assert_eq!(canonical_basis_size(&context), pseudo_intents, "{path}");
+        }
+    }
+
+    #[test]
+    fn closure_system_size_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let basis = canonical_basis(&context);
+
+        let closed = (0..context.attributes.len())
+            .powerset()
+            .map(|ms| ms.into_iter().collect::<BitSet>())
+            .filter(|sub| context.index_attribute_hull(sub) == *sub)
+            .count();
+        assert_eq!(context.closure_system_size(), closed);
+        assert_eq!(
+            implication_closure_system_size(&basis, context.attributes.len()),
+            closed
+        );
+
+        // {1} -> {1,2}
+        let implications = vec![(bitset_from_indices(&[1]), bitset_from_indices(&[1, 2]))];
+        let closed = (0..3)
+            .powerset()
+            .map(|ms| ms.into_iter().collect::<BitSet>())
+            .filter(|sub| implication_closure(&implications, sub) == *sub)
+            .count();
+        assert_eq!(implication_closure_system_size(&implications, 3), closed);
+    }
+
+    #[test]
+    fn canonical_basis_progress_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        let mut reported = Vec::new();
+        let output = canonical_basis_with_progress(&context, &mut |count| reported.push(count));
+
+        assert_eq!(output, canonical_basis(&context));
+        assert_eq!(reported, (1..output.len() + 1).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn canonical_basis_cancellable_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert_eq!(
+            canonical_basis_cancellable(&context, &|| false),
+            canonical_basis(&context)
         );
+        assert!(canonical_basis_cancellable(&context, &|| true).is_empty());
+    }
+
+    #[test]
+    fn implications_up_to_fixtures() {
+        let fixtures = [
+            (
+                "test_data/triangles.cxt",
+                1,
+                vec![(bitset_from_indices(&[0]), bitset_from_indices(&[0, 1, 2]))],
+            ),
+            // The canonical basis has no premises with exactly one attribute
+            (
+                "test_data/living_beings_and_water.cxt",
+                2,
+                vec![
+                    (BitSet::new(), bitset_from_indices(&[0])),
+                    (
+                        bitset_from_indices(&[0, 4]),
+                        bitset_from_indices(&[0, 2, 3, 4]),
+                    ),
+                    (
+                        bitset_from_indices(&[0, 5]),
+                        bitset_from_indices(&[0, 3, 5]),
+                    ),
+                    (
+                        bitset_from_indices(&[0, 7]),
+                        bitset_from_indices(&[0, 6, 7]),
+                    ),
+                    (
+                        bitset_from_indices(&[0, 8]),
+                        bitset_from_indices(&[0, 2, 6, 7, 8]),
+                    ),
+                ],
+            ),
+            (
+                "test_data/eu.cxt",
+                1,
+                vec![
+                    (bitset_from_indices(&[0]), bitset_from_indices(&[0, 5, 6])),
+                    (bitset_from_indices(&[2]), bitset_from_indices(&[2, 5, 6])),
+                    (bitset_from_indices(&[3]), bitset_from_indices(&[3, 5, 6])),
+                    (
+                        bitset_from_indices(&[4]),
+                        bitset_from_indices(&[2, 4, 5, 6]),
+                    ),
+                    (bitset_from_indices(&[6]), bitset_from_indices(&[5, 6])),
+                ],
+            ),
+        ];
+
+        for (path, max_premise, expected) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            assert_eq!(
+                implications_up_to(&context, max_premise),
+                expected,
+                "{path}"
+            );
+
+            let bounded: Vec<_> = implications_up_to(&context, context.attributes.len())
+                .into_iter()
+                .sorted_by_key(|(premise, _)| premise.iter().collect::<Vec<_>>())
+                .collect();
+            let full: Vec<_> = canonical_basis(&context)
+                .into_iter()
+                .sorted_by_key(|(premise, _)| premise.iter().collect::<Vec<_>>())
+                .collect();
+            assert_eq!(bounded, full, "{path}");
+        }
+    }
+
+    #[test]
+    fn minimum_cover_size() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 4),
+            ("test_data/living_beings_and_water.cxt", 10),
+            ("test_data/eu.cxt", 9),
+            ("test_data/data_from_paper.cxt", 4),
+        ];
+
+        for (path, pseudo_intents) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let basis = canonical_basis(&context);
+            let cover = minimum_cover(&basis);
+
+            assert_eq!(cover.len(), pseudo_intents, "{path}");
+            for (premise, conclusion) in &cover {
+                assert!(premise.is_disjoint(conclusion), "{path}");
+                assert!(entails(&basis, (premise, conclusion)), "{path}");
+            }
+            for (premise, conclusion) in &basis {
+                assert!(entails(&cover, (premise, conclusion)), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn minimum_cover_triangles() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let cover = minimum_cover(&canonical_basis(&context));
+
+        // {3,4} -> {2} and {2,4} -> {3} give {2,3,4}, from which {2,3} -> {0,4} and {0} -> {1,2} give the rest
+        assert_eq!(
+            cover,
+            vec![
+                (bitset_from_indices(&[3, 4]), bitset_from_indices(&[2])),
+                (bitset_from_indices(&[2, 4]), bitset_from_indices(&[3])),
+                (bitset_from_indices(&[2, 3]), bitset_from_indices(&[0, 4])),
+                (bitset_from_indices(&[0]), bitset_from_indices(&[1, 2])),
+            ]
+        );
+    }
+
+    #[test]
+    fn minimum_cover_merges_equivalent_premises() {
+        // {0,2} and {1,2} have the same closure and {0} -> {1} lets {0,2} determine {1,2}, so Maier's algorithm
+        // merges {0,2} -> {3} and {1,2} -> {4} into one implication
+        let implications = vec![
+            (bitset_from_indices(&[0]), bitset_from_indices(&[1])),
+            (bitset_from_indices(&[1]), bitset_from_indices(&[0])),
+            (bitset_from_indices(&[0, 2]), bitset_from_indices(&[3])),
+            (bitset_from_indices(&[1, 2]), bitset_from_indices(&[4])),
+        ];
+        let cover = minimum_cover(&implications);
+
+        assert_eq!(
+            cover,
+            vec![
+                (bitset_from_indices(&[0]), bitset_from_indices(&[1])),
+                (bitset_from_indices(&[1]), bitset_from_indices(&[0])),
+                (bitset_from_indices(&[1, 2]), bitset_from_indices(&[3, 4])),
+            ]
+        );
+        for (premise, conclusion) in &cover {
+            assert!(entails(&implications, (premise, conclusion)));
+        }
+        for (premise, conclusion) in &implications {
+            assert!(entails(&cover, (premise, conclusion)));
+        }
+    }
+
+    #[test]
+    fn implications_equivalent_small() {
+        // {0} -> {1} and {1} -> {2} entail {0} -> {1,2}, but {0} -> {1} alone does not entail {1} -> {2}
+        let chain = vec![
+            (bitset_from_indices(&[0]), bitset_from_indices(&[1])),
+            (bitset_from_indices(&[1]), bitset_from_indices(&[2])),
+        ];
+        let closed = vec![
+            (bitset_from_indices(&[0]), bitset_from_indices(&[1, 2])),
+            (bitset_from_indices(&[1]), bitset_from_indices(&[2])),
+        ];
+
+        assert!(implications_equivalent(&chain, &closed));
+        assert!(!implications_equivalent(&chain, &chain[..1]));
+        assert!(implications_equivalent(&[], &[]));
+    }
+
+    #[test]
+    fn implications_equivalent_fixtures() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 4),
+            ("test_data/living_beings_and_water.cxt", 10),
+            ("test_data/eu.cxt", 9),
+            ("test_data/data_from_paper.cxt", 4),
+        ];
+
+        for (path, pseudo_intents) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let basis = canonical_basis(&context);
+            assert_eq!(basis.len(), pseudo_intents, "{path}");
+
+            // Every implication of the context can be added without changing the closure operator
+            let mut redundant = basis.clone();
+            for m in 0..context.attributes.len() {
+                let premise = bitset_from_indices(&[m]);
+                redundant.push((premise.clone(), context.index_attribute_hull(&premise)));
+            }
+            assert!(implications_equivalent(&basis, &redundant), "{path}");
+            assert!(
+                implications_equivalent(&basis, &minimum_cover(&basis)),
+                "{path}"
+            );
+
+            // The canonical basis has no redundant implication, so dropping any one changes the closure operator
+            for index in 0..basis.len() {
+                let mut smaller = basis.clone();
+                smaller.remove(index);
+                assert!(!implications_equivalent(&basis, &smaller), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn local_basis_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let scope = bitset_from_indices(&[0, 2, 3, 5]);
+        let kept: Vec<usize> = scope.iter().collect();
+
+        let zoomed_basis: Vec<(BitSet, BitSet)> = canonical_basis(&context.attribute_zoom(&scope))
+            .iter()
+            .map(|(premise, conclusion)| {
+                (
+                    premise.iter().map(|m| kept[m]).collect(),
+                    conclusion.iter().map(|m| kept[m]).collect(),
+                )
+            })
+            .collect();
+        let basis = local_basis(&context, &scope);
+
+        assert_eq!(basis, zoomed_basis);
+        for (premise, conclusion) in &basis {
+            assert!(premise.is_subset(&scope) && conclusion.is_subset(&scope));
+            assert!(context.implication_holds(premise, conclusion));
+        }
     }
 }
@@ -0,0 +1,99 @@
+use bit_set::BitSet;
+
+use crate::FormalContext;
+
+use super::{canonical_basis, fcbo};
+
+// A positive hypothesis is an intent shared by some positive objects which is contained in no negative object intent
+// Only the inclusion minimal hypotheses are returned, following the JSM method
+pub fn minimal_hypotheses<T>(
+    context: &FormalContext<T>,
+    positive: &BitSet,
+    negative: &BitSet,
+) -> Vec<BitSet> {
+    let restricted = context.object_restriction(positive);
+
+    let mut hypotheses: Vec<BitSet> = fcbo::fcbo_concepts(&restricted)
+        .filter(|(extent, _)| !extent.is_empty())
+        .map(|(_, intent)| intent)
+        .filter(|intent| {
+            negative
+                .iter()
+                .all(|g| !intent.is_subset(&context.atomic_object_derivations[g]))
+        })
+        .collect();
+
+    hypotheses.sort_by_key(|x| x.len());
+    let mut output: Vec<BitSet> = Vec::new();
+    for hypothesis in hypotheses {
+        if !output.iter().any(|x| x.is_subset(&hypothesis)) {
+            output.push(hypothesis);
+        }
+    }
+    output
+}
+
+// Predicts the attributes of a new object as the closure of its known attributes under the implications
+pub fn classify(implications: &[(BitSet, BitSet)], object_attributes: &BitSet) -> BitSet {
+    canonical_basis::implication_closure(implications, object_attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use bit_set::BitSet;
+    use std::fs;
+
+    use crate::{
+        algorithms::classification::{classify, minimal_hypotheses},
+        FormalContext,
+    };
+
+    #[test]
+    fn test_minimal_hypotheses() {
+        let context = FormalContext::<String>::from(
+            b"B\n\n5\n4\n\np1\np2\np3\nn1\nn2\na\nb\nc\nd\nXX..\nXXX.\n..XX\n.XX.\n..X.\n",
+        )
+        .unwrap();
+        let positive: BitSet = [0, 1, 2].into_iter().collect();
+        let negative: BitSet = [3, 4].into_iter().collect();
+
+        let hypotheses = minimal_hypotheses(&context, &positive, &negative);
+
+        // {a, b} and {c, d}
+        assert_eq!(
+            hypotheses,
+            vec![
+                [0, 1].into_iter().collect::<BitSet>(),
+                [2, 3].into_iter().collect::<BitSet>()
+            ]
+        );
+        for g in &positive {
+            assert!(hypotheses
+                .iter()
+                .any(|x| x.is_subset(&context.atomic_object_derivations[g])));
+        }
+        for g in &negative {
+            assert!(hypotheses
+                .iter()
+                .all(|x| !x.is_subset(&context.atomic_object_derivations[g])));
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let basis = context.canonical_basis();
+
+        // {0} -> {0,1,2}
+        let object_attributes: BitSet = [0].into_iter().collect();
+        assert_eq!(
+            classify(&basis, &object_attributes),
+            [0, 1, 2].into_iter().collect()
+        );
+
+        // {1} matches no premise
+        let object_attributes: BitSet = [1].into_iter().collect();
+        assert_eq!(classify(&basis, &object_attributes), object_attributes);
+    }
+}
@@ -0,0 +1,418 @@
+use bit_set::BitSet;
+use std::collections::HashSet;
+
+use crate::FormalContext;
+
+use super::fcbo;
+
+// The maximal rectangles of crosses are exactly the concepts with non-empty extent and intent
+pub fn maximal_rectangles<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
+    fcbo::fcbo_concepts(context)
+        .filter(|(extent, intent)| !extent.is_empty() && !intent.is_empty())
+        .collect()
+}
+
+// Tracks the concept with the largest area in one pass, keeping the first of several equally large ones
+// A context without crosses has no concept with a positive area
+pub fn largest_concept<T>(context: &FormalContext<T>) -> Option<(BitSet, BitSet)> {
+    let mut largest: Option<(BitSet, BitSet)> = None;
+    let mut largest_area = 0;
+    for (extent, intent) in fcbo::fcbo_concepts(context) {
+        let area = extent.len() * intent.len();
+        if area > largest_area {
+            largest_area = area;
+            largest = Some((extent, intent));
+        }
+    }
+    largest
+}
+
+fn covered_crosses(rectangle: &(BitSet, BitSet), uncovered: &HashSet<(usize, usize)>) -> usize {
+    rectangle
+        .0
+        .iter()
+        .map(|g| {
+            rectangle
+                .1
+                .iter()
+                .filter(|&m| uncovered.contains(&(g, m)))
+                .count()
+        })
+        .sum()
+}
+
+// Greedily picks the maximal rectangle covering the most crosses not covered yet, until all crosses are covered
+// This is the usual set cover heuristic, the result is not guaranteed to be a minimum cover
+pub fn minimum_rectangle_cover<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
+    let mut rectangles = maximal_rectangles(context);
+    let mut uncovered = context.incidence.clone();
+    let mut cover = Vec::new();
+
+    while !uncovered.is_empty() {
+        let (index, _) = rectangles
+            .iter()
+            .enumerate()
+            .map(|(index, rectangle)| (index, covered_crosses(rectangle, &uncovered)))
+            .max_by_key(|&(_, count)| count)
+            .unwrap();
+        let rectangle = rectangles.swap_remove(index);
+        remove_covered(&rectangle, &mut uncovered);
+        cover.push(rectangle);
+    }
+    cover
+}
+
+fn remove_covered(rectangle: &(BitSet, BitSet), uncovered: &mut HashSet<(usize, usize)>) {
+    for g in &rectangle.0 {
+        for m in &rectangle.1 {
+            uncovered.remove(&(g, m));
+        }
+    }
+}
+
+// Returns the crosses which lie in none of the factors
+pub fn uncovered_incidence<T>(
+    context: &FormalContext<T>,
+    factors: &[(BitSet, BitSet)],
+) -> HashSet<(usize, usize)> {
+    let mut uncovered = context.incidence.clone();
+    for factor in factors {
+        remove_covered(factor, &mut uncovered);
+    }
+    uncovered
+}
+
+// GreConD grows every factor attribute by attribute, always taking the attribute whose concept covers
+// the most crosses not covered yet, and stops growing as soon as no attribute improves the coverage
+// Returns the factors together with the fraction of the crosses they cover
+pub fn grecond<T>(context: &FormalContext<T>, k: usize) -> (Vec<(BitSet, BitSet)>, f64) {
+    let mut uncovered = context.incidence.clone();
+    let mut factors: Vec<(BitSet, BitSet)> = Vec::new();
+
+    while factors.len() < k && !uncovered.is_empty() {
+        let mut factor = (
+            context.index_attribute_derivation(&BitSet::new()),
+            context.index_attribute_hull(&BitSet::new()),
+        );
+        let mut coverage = covered_crosses(&factor, &uncovered);
+
+        loop {
+            let best = context
+                .all_attributes()
+                .difference(&factor.1)
+                .map(|m| {
+                    let mut intent = factor.1.clone();
+                    intent.insert(m);
+                    let extent = context.index_attribute_derivation(&intent);
+                    let intent = context.index_object_derivation(&extent);
+                    let candidate = (extent, intent);
+                    let count = covered_crosses(&candidate, &uncovered);
+                    (candidate, count)
+                })
+                .max_by_key(|(_, count)| *count);
+
+            match best {
+                Some((candidate, count)) if count > coverage => {
+                    factor = candidate;
+                    coverage = count;
+                }
+                _ => break,
+            }
+        }
+
+        if coverage == 0 {
+            break;
+        }
+        remove_covered(&factor, &mut uncovered);
+        factors.push(factor);
+    }
+
+    let coverage = if context.incidence.is_empty() {
+        1.0
+    } else {
+        1.0 - uncovered.len() as f64 / context.incidence.len() as f64
+    };
+    (factors, coverage)
+}
+
+// The share of crosses in the block, an empty block counts as dense
+fn density<T>(context: &FormalContext<T>, block: &(BitSet, BitSet)) -> f64 {
+    let area = block.0.len() * block.1.len();
+    if area == 0 {
+        return 1.0;
+    }
+    let crosses: usize = block
+        .0
+        .iter()
+        .map(|g| {
+            context.atomic_object_derivations[g]
+                .intersection(&block.1)
+                .count()
+        })
+        .sum();
+    crosses as f64 / area as f64
+}
+
+// Grows every concept greedily by the object or attribute keeping the block densest, as long as the density
+// stays at the threshold. A concept can not be grown without losing a cross, so a threshold of 1 keeps the concepts
+pub fn dense_biclusters<T>(
+    context: &FormalContext<T>,
+    min_density: f64,
+    min_rows: usize,
+    min_cols: usize,
+) -> Vec<(BitSet, BitSet)> {
+    let mut biclusters: Vec<(BitSet, BitSet)> = Vec::new();
+    for mut block in fcbo::fcbo_concepts(context) {
+        loop {
+            let mut best: Option<((BitSet, BitSet), f64)> = None;
+            let candidates = context
+                .all_objects()
+                .difference(&block.0)
+                .map(|g| {
+                    let mut extent = block.0.clone();
+                    extent.insert(g);
+                    (extent, block.1.clone())
+                })
+                .chain(context.all_attributes().difference(&block.1).map(|m| {
+                    let mut intent = block.1.clone();
+                    intent.insert(m);
+                    (block.0.clone(), intent)
+                }));
+            for candidate in candidates {
+                if candidate.0.is_empty() || candidate.1.is_empty() {
+                    continue;
+                }
+                let candidate_density = density(context, &candidate);
+                if candidate_density >= min_density
+                    && best.as_ref().is_none_or(|(_, d)| candidate_density > *d)
+                {
+                    best = Some((candidate, candidate_density));
+                }
+            }
+            match best {
+                Some((grown, _)) => block = grown,
+                None => break,
+            }
+        }
+
+        if block.0.len() >= min_rows && block.1.len() >= min_cols && !biclusters.contains(&block) {
+            biclusters.push(block);
+        }
+    }
+    biclusters
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs};
+
+    use crate::{
+        algorithms::factorization::{
+            dense_biclusters, density, grecond, largest_concept, maximal_rectangles,
+            minimum_rectangle_cover, uncovered_incidence,
+        },
+        util::bitset_from_indices,
+        FormalContext,
+    };
+
+    #[test]
+    fn rectangles_cover_incidence() {
+        // Triangles: all concepts except the top and the bottom, no four of them cover the 12 crosses
+        // Living beings and water: no six of the maximal rectangles cover the 34 crosses
+        let fixtures = [
+            ("test_data/triangles.cxt", 8, 5),
+            ("test_data/living_beings_and_water.cxt", 18, 7),
+            ("test_data/eu.cxt", 17, 7),
+        ];
+        for (path, rectangle_count, min_cover_size) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let rectangles = maximal_rectangles(&context);
+            let cover = minimum_rectangle_cover(&context);
+
+            for (extent, intent) in &rectangles {
+                assert!(!extent.is_empty() && !intent.is_empty(), "{path}");
+                assert_eq!(context.index_object_derivation(extent), *intent, "{path}");
+            }
+            for &(g, m) in &context.incidence {
+                assert!(
+                    rectangles
+                        .iter()
+                        .any(|(extent, intent)| extent.contains(g) && intent.contains(m)),
+                    "{path}"
+                );
+                assert!(
+                    cover
+                        .iter()
+                        .any(|(extent, intent)| extent.contains(g) && intent.contains(m)),
+                    "{path}"
+                );
+            }
+
+            assert_eq!(rectangles.len(), rectangle_count, "{path}");
+            assert!(
+                cover.len() >= min_cover_size && cover.len() <= rectangles.len(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn rectangle_cover_small() {
+        // {a,c,d} x {x} covers the most crosses, after it {b,c} x {y} covers both remaining ones
+        let context =
+            FormalContext::<String>::from(b"B\n\n4\n2\n\na\nb\nc\nd\nx\ny\nX.\n.X\nXX\nX.\n")
+                .unwrap();
+
+        let cover = minimum_rectangle_cover(&context);
+
+        assert_eq!(maximal_rectangles(&context).len(), 3);
+        assert_eq!(
+            cover,
+            vec![
+                (bitset_from_indices(&[0, 2, 3]), bitset_from_indices(&[0])),
+                (bitset_from_indices(&[1, 2]), bitset_from_indices(&[1])),
+            ]
+        );
+    }
+
+    #[test]
+    fn grecond_covers_incidence() {
+        // Triangles: the concept of attribute 1 covers 4 of the 12 crosses, adding attribute 2 does not cover more
+        let fixtures = [
+            ("test_data/triangles.cxt", vec![1], 4.0 / 12.0),
+            (
+                "test_data/living_beings_and_water.cxt",
+                vec![0, 2],
+                10.0 / 34.0,
+            ),
+            ("test_data/eu.cxt", vec![5, 6], 86.0 / 201.0),
+            (
+                "test_data/data_from_paper.cxt",
+                vec![0, 2, 3, 4, 5],
+                5.0 / 13.0,
+            ),
+        ];
+        for (path, first_intent, first_coverage) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            let (factors, coverage) = grecond(&context, usize::MAX);
+            assert_eq!(coverage, 1.0, "{path}");
+            for (extent, intent) in &factors {
+                assert_eq!(context.index_object_derivation(extent), *intent, "{path}");
+            }
+            for &(g, m) in &context.incidence {
+                assert!(
+                    factors
+                        .iter()
+                        .any(|(extent, intent)| extent.contains(g) && intent.contains(m)),
+                    "{path}"
+                );
+            }
+
+            let (factors, coverage) = grecond(&context, 1);
+            let intent = bitset_from_indices(&first_intent);
+            assert_eq!(
+                factors,
+                vec![(context.index_attribute_derivation(&intent), intent)],
+                "{path}"
+            );
+            assert!((coverage - first_coverage).abs() < 1e-9, "{path}");
+        }
+    }
+
+    #[test]
+    fn uncovered_incidence_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        let concepts: Vec<_> = context.fcbo_index_concepts().collect();
+        assert!(uncovered_incidence(&context, &concepts).is_empty());
+        assert_eq!(uncovered_incidence(&context, &[]), context.incidence);
+
+        let factor = (
+            bitset_from_indices(&[0, 1, 3, 5]),
+            bitset_from_indices(&[1]),
+        );
+        let remainder: HashSet<(usize, usize)> = [
+            (0, 3),
+            (1, 4),
+            (2, 2),
+            (3, 0),
+            (3, 2),
+            (4, 3),
+            (5, 2),
+            (6, 4),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(uncovered_incidence(&context, &[factor]), remainder);
+    }
+
+    #[test]
+    fn dense_biclusters_small() {
+        // Three of the four cells are crosses, so every concept grows into the whole table at density 0.7
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nXX\nX.\n").unwrap();
+
+        assert_eq!(
+            dense_biclusters(&context, 0.7, 0, 0),
+            vec![(bitset_from_indices(&[0, 1]), bitset_from_indices(&[0, 1]))]
+        );
+        assert_eq!(dense_biclusters(&context, 0.8, 2, 2), vec![]);
+    }
+
+    #[test]
+    fn dense_biclusters_fixtures() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+            ("test_data/data_from_paper.cxt", 12),
+        ];
+        for (path, concept_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            assert_eq!(
+                dense_biclusters(&context, 1.0, 0, 0).len(),
+                concept_count,
+                "{path}"
+            );
+            for (min_rows, min_cols) in [(0, 0), (1, 1), (2, 2)] {
+                let biclusters: HashSet<_> = dense_biclusters(&context, 1.0, min_rows, min_cols)
+                    .into_iter()
+                    .collect();
+                let concepts: HashSet<_> = context
+                    .fcbo_index_concepts()
+                    .filter(|(extent, intent)| extent.len() >= min_rows && intent.len() >= min_cols)
+                    .collect();
+                assert_eq!(biclusters, concepts, "{path}");
+            }
+
+            for block in dense_biclusters(&context, 0.7, 2, 2) {
+                assert!(density(&context, &block) >= 0.7, "{path}");
+                assert!(block.0.len() >= 2 && block.1.len() >= 2, "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn largest_concept_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let largest = largest_concept(&context).unwrap();
+        let (extent, intent) = &largest;
+
+        // {3,5} x {1,2} and {0,1,3,5} x {1} both have the largest area 4
+        assert_eq!(extent.len() * intent.len(), 4);
+        assert!(
+            largest == (bitset_from_indices(&[3, 5]), bitset_from_indices(&[1, 2]))
+                || largest
+                    == (
+                        bitset_from_indices(&[0, 1, 3, 5]),
+                        bitset_from_indices(&[1])
+                    )
+        );
+
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\n..\n..\n").unwrap();
+        assert_eq!(largest_concept(&context), None);
+    }
+}
@@ -0,0 +1,92 @@
+use bit_set::BitSet;
+use std::collections::HashSet;
+
+use crate::{FormalContext, ManyValuedContext};
+
+// The attributes on which two objects agree form one row for every pair of objects
+// Objects and attributes share their name type, so the attributes are named by their index twice
+pub fn agreement_context<T, V: PartialEq>(
+    values: &ManyValuedContext<T, V>,
+) -> FormalContext<(usize, usize)> {
+    let mut pairs = Vec::new();
+    let mut incidence = HashSet::new();
+    for g in 0..values.objects.len() {
+        for h in g + 1..values.objects.len() {
+            for m in 0..values.attributes.len() {
+                if values.value(g, m) == values.value(h, m) {
+                    incidence.insert((pairs.len(), m));
+                }
+            }
+            pairs.push((g, h));
+        }
+    }
+
+    FormalContext::construct(
+        pairs,
+        (0..values.attributes.len()).map(|m| (m, m)).collect(),
+        incidence,
+    )
+}
+
+// An implication holds in the agreement context exactly when it holds as a functional dependency
+pub fn functional_dependencies<T, V: PartialEq>(
+    values: &ManyValuedContext<T, V>,
+) -> Vec<(BitSet, BitSet)> {
+    agreement_context(values).canonical_basis()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::functional_dependencies::{agreement_context, functional_dependencies},
+        util::bitset_from_indices,
+        ManyValuedContext,
+    };
+
+    #[test]
+    fn functional_dependencies_test() {
+        let values = ManyValuedContext::new(
+            vec!["0", "1", "2", "3"],
+            vec!["a", "b", "c"],
+            vec![
+                vec![1, 10, 100],
+                vec![1, 10, 200],
+                vec![2, 20, 200],
+                vec![3, 20, 100],
+            ],
+        );
+
+        let dependencies = functional_dependencies(&values);
+
+        // a determines b, and b together with c determines everything
+        assert_eq!(dependencies.len(), 2);
+        assert!(dependencies.contains(&(bitset_from_indices(&[0]), bitset_from_indices(&[0, 1]))));
+        assert!(dependencies.contains(&(
+            bitset_from_indices(&[1, 2]),
+            bitset_from_indices(&[0, 1, 2])
+        )));
+    }
+
+    #[test]
+    fn agreement_context_test() {
+        for n in [0usize, 1, 2, 5] {
+            let values = ManyValuedContext::new(
+                (0..n).collect(),
+                vec![0, 1],
+                (0..n).map(|g| vec![g % 2, 0]).collect(),
+            );
+
+            let context = agreement_context(&values);
+
+            assert_eq!(context.objects.len(), n * n.saturating_sub(1) / 2);
+            for (pair, (g, h)) in context.objects.iter().enumerate() {
+                assert!(g < h);
+                assert_eq!(
+                    context.atomic_object_derivations[pair].contains(0),
+                    g % 2 == h % 2
+                );
+                assert!(context.atomic_object_derivations[pair].contains(1));
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use bit_set::BitSet;
+
+use crate::{ConceptLattice, FormalContext};
+
+// Two lattices are isomorphic exactly when their standard contexts are the same up to renaming
+fn standard_context<T>(context: &FormalContext<T>) -> (Vec<BitSet>, usize) {
+    let standard_context = ConceptLattice::new(context).irreducible_context();
+    (
+        standard_context.atomic_object_derivations,
+        standard_context.attributes.len(),
+    )
+}
+
+// Assigns the rows one after another, a partial assignment is only kept if it preserves
+// the row sizes and the number of columns shared by every two assigned rows
+fn assign_rows(
+    rows: &[BitSet],
+    other_rows: &[BitSet],
+    column_count: usize,
+    assignment: &mut Vec<usize>,
+) -> bool {
+    let row = assignment.len();
+    if row == rows.len() {
+        let mut columns: Vec<BitSet> = columns_of(rows, column_count);
+        let mut other_columns: Vec<BitSet> = columns_of(
+            &assignment
+                .iter()
+                .map(|&other_row| other_rows[other_row].clone())
+                .collect::<Vec<_>>(),
+            column_count,
+        );
+        columns.sort();
+        other_columns.sort();
+        return columns == other_columns;
+    }
+
+    for candidate in 0..other_rows.len() {
+        if assignment.contains(&candidate) || rows[row].len() != other_rows[candidate].len() {
+            continue;
+        }
+        let consistent = assignment.iter().enumerate().all(|(previous, &other)| {
+            rows[row].intersection(&rows[previous]).count()
+                == other_rows[candidate]
+                    .intersection(&other_rows[other])
+                    .count()
+        });
+        if consistent {
+            assignment.push(candidate);
+            if assign_rows(rows, other_rows, column_count, assignment) {
+                return true;
+            }
+            assignment.pop();
+        }
+    }
+    false
+}
+
+fn columns_of(rows: &[BitSet], column_count: usize) -> Vec<BitSet> {
+    (0..column_count)
+        .map(|m| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| row.contains(m))
+                .map(|(g, _)| g)
+                .collect()
+        })
+        .collect()
+}
+
+// Compares the standard contexts by searching for a bijection of their rows, which is exponential
+// in the worst case and meant for small lattices
+pub fn lattice_isomorphic<T, U>(context: &FormalContext<T>, other: &FormalContext<U>) -> bool {
+    let (rows, column_count) = standard_context(context);
+    let (other_rows, other_column_count) = standard_context(other);
+
+    if rows.len() != other_rows.len() || column_count != other_column_count {
+        return false;
+    }
+    assign_rows(&rows, &other_rows, column_count, &mut Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        algorithms::isomorphism::lattice_isomorphic, util::bitset_from_indices, FormalContext,
+    };
+    use std::fs;
+
+    #[test]
+    fn clarified_context_is_isomorphic() {
+        // The new object's intent is not closed in the fixture, so it adds a concept
+        let fixtures = [
+            ("test_data/triangles.cxt", [0, 3]),
+            ("test_data/living_beings_and_water.cxt", [1, 2]),
+            ("test_data/data_from_paper.cxt", [0, 3]),
+        ];
+        for (path, new_intent) in fixtures {
+            let clarified = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let mut context = clarified.clone();
+            context.add_object(
+                "copy".to_string(),
+                &context.atomic_object_derivations[0].clone(),
+            );
+
+            assert!(lattice_isomorphic(&context, &clarified), "{path}");
+
+            let mut other = clarified.clone();
+            other.add_object("new".to_string(), &bitset_from_indices(&new_intent));
+
+            assert!(!lattice_isomorphic(&clarified, &other), "{path}");
+        }
+    }
+
+    #[test]
+    fn small_lattices() {
+        // Both are the four element Boolean lattice, the third context is a three element chain
+        let identity = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\n.X\n").unwrap();
+        let swapped = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\n.X\nX.\n").unwrap();
+        let chain = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\nXX\n").unwrap();
+
+        assert!(lattice_isomorphic(&identity, &swapped));
+        assert!(!lattice_isomorphic(&identity, &chain));
+    }
+}
@@ -0,0 +1,143 @@
+use bit_set::BitSet;
+use std::collections::HashSet;
+
+use crate::{algorithms::proper_premises::minimal_transversals, FormalContext};
+
+// An attribute set closes to all attributes exactly when no object outside the bottom extent has all of it,
+// so the keys are the minimal transversals of the attribute sets missing from those objects
+pub fn keys<T>(context: &FormalContext<T>) -> Vec<BitSet> {
+    let all_attributes = context.all_attributes();
+
+    let edges: Vec<BitSet> = context
+        .atomic_object_derivations
+        .iter()
+        .filter(|intent| *intent != all_attributes)
+        .map(|intent| all_attributes.difference(intent).collect())
+        .collect();
+
+    minimal_transversals(&edges)
+}
+
+// A set of attributes distinguishes two objects exactly when it meets the symmetric difference of their intents,
+// so the smallest minimal transversal of these differences is a minimum set distinguishing all objects
+pub fn minimal_distinguishing_attributes<T>(context: &FormalContext<T>) -> Option<BitSet> {
+    let mut edges: HashSet<BitSet> = HashSet::new();
+    for (g, intent) in context.atomic_object_derivations.iter().enumerate() {
+        for other in &context.atomic_object_derivations[g + 1..] {
+            edges.insert(intent.symmetric_difference(other).collect());
+        }
+    }
+    if edges.contains(&BitSet::new()) {
+        return None;
+    }
+
+    let edges: Vec<BitSet> = edges.into_iter().collect();
+    minimal_transversals(&edges)
+        .into_iter()
+        .min_by_key(|transversal| transversal.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+    use std::collections::HashSet;
+
+    use crate::{
+        algorithms::keys::{keys, minimal_distinguishing_attributes},
+        util::bitset_from_indices,
+        FormalContext,
+    };
+
+    #[test]
+    fn keys_fixtures() {
+        let fixtures: [(&str, &[&[usize]]); 4] = [
+            (
+                "test_data/triangles.cxt",
+                &[&[0, 3], &[0, 4], &[2, 3], &[2, 4], &[3, 4]],
+            ),
+            (
+                "test_data/living_beings_and_water.cxt",
+                &[
+                    &[1, 4],
+                    &[1, 8],
+                    &[3, 6],
+                    &[3, 7],
+                    &[3, 8],
+                    &[4, 5],
+                    &[4, 6],
+                    &[4, 7],
+                    &[4, 8],
+                    &[5, 6],
+                    &[5, 7],
+                    &[5, 8],
+                ],
+            ),
+            ("test_data/eu.cxt", &[&[0, 4], &[1, 4]]),
+            (
+                "test_data/data_from_paper.cxt",
+                &[&[1, 3], &[1, 5], &[1, 2, 4]],
+            ),
+        ];
+        for (path, expected) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let all_attributes: BitSet = (0..context.attributes.len()).collect();
+            let keys = keys(&context);
+
+            let expected: HashSet<BitSet> = expected
+                .iter()
+                .map(|key| bitset_from_indices(key))
+                .collect();
+            assert_eq!(keys.len(), expected.len(), "{path}");
+            assert_eq!(
+                keys.iter().cloned().collect::<HashSet<_>>(),
+                expected,
+                "{path}"
+            );
+            for key in &keys {
+                assert_eq!(context.index_attribute_hull(key), all_attributes, "{path}");
+                for m in key {
+                    let mut smaller = key.clone();
+                    smaller.remove(m);
+                    assert_ne!(
+                        context.index_attribute_hull(&smaller),
+                        all_attributes,
+                        "{path}"
+                    );
+                }
+                for other in &keys {
+                    assert!(key == other || !key.is_subset(other), "{path}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn distinguishing_attributes() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        assert_eq!(minimal_distinguishing_attributes(&context), None);
+
+        context.dedup_objects();
+        let attributes = minimal_distinguishing_attributes(&context).unwrap();
+        let rows: HashSet<BitSet> = context
+            .atomic_object_derivations
+            .iter()
+            .map(|intent| intent.intersection(&attributes).collect())
+            .collect();
+
+        assert_eq!(rows.len(), context.objects.len());
+        for m in &attributes {
+            let mut smaller = attributes.clone();
+            smaller.remove(m);
+            let rows: HashSet<BitSet> = context
+                .atomic_object_derivations
+                .iter()
+                .map(|intent| intent.intersection(&smaller).collect())
+                .collect();
+            assert!(rows.len() < context.objects.len());
+        }
+    }
+}
@@ -1,45 +1,321 @@
 use bit_set::BitSet;
+use std::collections::HashSet;
 
-use crate::FormalContext;
+use crate::{FormalContext, ManyValuedContext};
 
+pub mod arrow_relations;
 pub mod attribute_exploration;
+pub mod block_relation;
 pub mod canonical_basis;
+pub mod classification;
+pub mod factorization;
 pub mod fcbo;
+pub mod functional_dependencies;
+pub mod isomorphism;
+pub mod keys;
 pub mod next_closure;
+pub mod proper_premises;
+pub mod sampling;
+pub mod statistics;
+pub mod subcontexts;
 pub mod upper_neighbor;
 
 impl<T> FormalContext<T> {
     pub fn index_concepts<'a>(&'a self) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
-        next_closure::concepts(&self)
+        next_closure::concepts(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn next_concept_from(&self, intent: &BitSet) -> Option<(BitSet, BitSet)> {
+        next_closure::next_concept_from(self, intent)
+    }
+
+    pub fn lectic_rank(&self, intent: &BitSet) -> usize {
+        next_closure::lectic_rank(self, intent)
+    }
+
+    pub fn nth_concept(&self, n: usize) -> Option<(BitSet, BitSet)> {
+        next_closure::nth_concept(self, n)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn index_object_concepts<'a>(&'a self) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
+        next_closure::object_concepts(self)
     }
 }
 
 impl<T> FormalContext<T> {
     pub fn fcbo_index_concepts<'a>(&'a self) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
-        fcbo::fcbo_concepts(&self)
+        fcbo::fcbo_concepts(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn fcbo_index_concepts_with_progress<'a>(
+        &'a self,
+        progress: &'a mut dyn FnMut(usize),
+    ) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
+        fcbo::fcbo_concepts_with_progress(self, progress)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn fcbo_index_concepts_cancellable<'a>(
+        &'a self,
+        should_cancel: &'a dyn Fn() -> bool,
+    ) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
+        fcbo::fcbo_concepts_cancellable(self, should_cancel)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn concepts_batched<'a>(
+        &'a self,
+        batch_size: usize,
+    ) -> impl Iterator<Item = Vec<(BitSet, BitSet)>> + 'a {
+        fcbo::fcbo_concepts_batched(self, batch_size)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn concepts_above(&self, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+        fcbo::fcbo_concepts_above(self, intent)
+    }
+
+    pub fn fcbo_concepts_ordered(&self, order: &[usize]) -> Vec<(BitSet, BitSet)> {
+        fcbo::fcbo_concepts_ordered(self, order)
+    }
+
+    pub fn recommended_attribute_order(&self) -> Vec<usize> {
+        fcbo::recommended_attribute_order(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn canonical_basis(&self) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::canonical_basis(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn canonical_basis_with_progress(
+        &self,
+        progress: &mut dyn FnMut(usize),
+    ) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::canonical_basis_with_progress(self, progress)
+    }
+
+    pub fn implications_up_to(&self, max_premise: usize) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::implications_up_to(self, max_premise)
+    }
+
+    pub fn local_basis(&self, scope: &BitSet) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::local_basis(self, scope)
+    }
+
+    pub fn canonical_basis_cancellable(
+        &self,
+        should_cancel: &dyn Fn() -> bool,
+    ) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::canonical_basis_cancellable(self, should_cancel)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn canonical_basis_size(&self) -> usize {
+        canonical_basis::canonical_basis_size(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    /// Returns the number of intents, which equals the number of concepts.
+    pub fn closure_system_size(&self) -> usize {
+        self.fcbo_index_concepts().count()
     }
 }
 
 impl<T> FormalContext<T> {
-    pub fn canonical_basis<'a>(&'a self) -> Vec<(BitSet, BitSet)> {
-        canonical_basis::canonical_basis(&self)
+    pub fn canonical_basis_optimised(&self) -> Vec<(BitSet, BitSet)> {
+        canonical_basis::canonical_basis_optimised(self)
     }
 }
 
 impl<T> FormalContext<T> {
-    pub fn canonical_basis_optimised<'a>(&'a self) -> Vec<(BitSet, BitSet)> {
-        canonical_basis::canonical_basis_optimised(&self)
+    pub fn unary_implications(&self) -> Vec<(BitSet, usize)> {
+        canonical_basis::unary_implications(self)
+    }
+}
+
+impl<T: Clone> FormalContext<T> {
+    pub fn block_relation(&self, incidence: &HashSet<(usize, usize)>) -> FormalContext<T> {
+        block_relation::block_relation(self, incidence)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn proper_premises(&self) -> Vec<(BitSet, BitSet)> {
+        proper_premises::proper_premises(self)
+    }
+
+    pub fn canonical_direct_basis(&self) -> Vec<(BitSet, BitSet)> {
+        proper_premises::canonical_direct_basis(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn extent_size_histogram(&self) -> Vec<usize> {
+        statistics::extent_size_histogram(self)
+    }
+
+    pub fn intent_size_stats(&self) -> (f64, usize) {
+        statistics::intent_size_stats(self)
+    }
+
+    pub fn influential_incidences(&self, top_k: usize) -> Vec<((usize, usize), isize)> {
+        statistics::influential_incidences(self, top_k)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn minimal_hypotheses(&self, positive: &BitSet, negative: &BitSet) -> Vec<BitSet> {
+        classification::minimal_hypotheses(self, positive, negative)
+    }
+
+    pub fn classify(
+        &self,
+        implications: &[(BitSet, BitSet)],
+        object_attributes: &BitSet,
+    ) -> BitSet {
+        classification::classify(implications, object_attributes)
     }
 }
 
 impl FormalContext<String> {
-    pub fn attribute_exploration<'a>(&mut self) -> Vec<(BitSet, BitSet)> {
+    pub fn attribute_exploration(&mut self) -> Vec<(BitSet, BitSet)> {
         attribute_exploration::attribute_exploration(self)
     }
+
+    pub fn attribute_exploration_plain(&mut self) -> Vec<(BitSet, BitSet)> {
+        attribute_exploration::attribute_exploration_plain(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn upper_neighbor(&self, input: &BitSet) -> BitSet {
+        upper_neighbor::upper_neighbor(input, self)
+    }
+
+    pub fn lower_neighbor(&self, input: &BitSet) -> BitSet {
+        upper_neighbor::lower_neighbor(input, self)
+    }
+
+    pub fn upper_covers(&self, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+        upper_neighbor::upper_covers(self, intent)
+    }
+
+    pub fn lower_covers(&self, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+        upper_neighbor::lower_covers(self, intent)
+    }
+
+    pub fn concepts_with_degrees(&self) -> Vec<((BitSet, BitSet), usize, usize)> {
+        upper_neighbor::concepts_with_degrees(self)
+    }
 }
 
 impl<T> FormalContext<T> {
-    pub fn upper_neighbor<'a>(&self, input: &BitSet) -> BitSet {
-        upper_neighbor::upper_neighbor(input, &self)
+    pub fn lattice_isomorphic<U>(&self, other: &FormalContext<U>) -> bool {
+        isomorphism::lattice_isomorphic(self, other)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn keys(&self) -> Vec<BitSet> {
+        keys::keys(self)
+    }
+
+    pub fn minimal_distinguishing_attributes(&self) -> Option<BitSet> {
+        keys::minimal_distinguishing_attributes(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn agreement_context<V: PartialEq>(
+        values: &ManyValuedContext<T, V>,
+    ) -> FormalContext<(usize, usize)> {
+        functional_dependencies::agreement_context(values)
+    }
+}
+
+impl<T, V: PartialEq> ManyValuedContext<T, V> {
+    pub fn functional_dependencies(&self) -> Vec<(BitSet, BitSet)> {
+        functional_dependencies::functional_dependencies(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn up_arrows(&self) -> HashSet<(usize, usize)> {
+        arrow_relations::up_arrows(self)
+    }
+
+    pub fn down_arrows(&self) -> HashSet<(usize, usize)> {
+        arrow_relations::down_arrows(self)
+    }
+
+    pub fn double_arrows(&self) -> HashSet<(usize, usize)> {
+        arrow_relations::double_arrows(self)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn maximal_rectangles(&self) -> Vec<(BitSet, BitSet)> {
+        factorization::maximal_rectangles(self)
+    }
+
+    pub fn minimum_rectangle_cover(&self) -> Vec<(BitSet, BitSet)> {
+        factorization::minimum_rectangle_cover(self)
+    }
+
+    pub fn uncovered_incidence(&self, factors: &[(BitSet, BitSet)]) -> HashSet<(usize, usize)> {
+        factorization::uncovered_incidence(self, factors)
+    }
+
+    pub fn grecond(&self, k: usize) -> (Vec<(BitSet, BitSet)>, f64) {
+        factorization::grecond(self, k)
+    }
+
+    pub fn largest_concept(&self) -> Option<(BitSet, BitSet)> {
+        factorization::largest_concept(self)
+    }
+
+    pub fn dense_biclusters(
+        &self,
+        min_density: f64,
+        min_rows: usize,
+        min_cols: usize,
+    ) -> Vec<(BitSet, BitSet)> {
+        factorization::dense_biclusters(self, min_density, min_rows, min_cols)
+    }
+}
+
+impl<T: Clone> FormalContext<T> {
+    pub fn compatible_subcontexts(&self, max: usize) -> Vec<FormalContext<T>> {
+        subcontexts::compatible_subcontexts(self, max)
+    }
+}
+
+impl<T> FormalContext<T> {
+    pub fn sample_concepts(&self, n: usize, seed: u64) -> Vec<(BitSet, BitSet)> {
+        sampling::sample_concepts(self, n, seed)
+    }
+
+    pub fn approximate_stability(
+        &self,
+        concept: &(BitSet, BitSet),
+        samples: usize,
+        seed: u64,
+    ) -> f64 {
+        sampling::approximate_stability(self, concept, samples, seed)
     }
 }
@@ -26,7 +26,15 @@ fn next_concept<T>(context: &FormalContext<T>, a: &BitSet) -> Option<(BitSet, Bi
         }
     }
 
-    return None;
+    None
+}
+
+// Closes the intent first, so the walk can be resumed from any attribute set
+pub fn next_concept_from<T>(
+    context: &FormalContext<T>,
+    intent: &BitSet,
+) -> Option<(BitSet, BitSet)> {
+    next_concept(context, &context.index_attribute_hull(intent))
 }
 
 pub fn concepts<'a, T>(
@@ -38,9 +46,58 @@ pub fn concepts<'a, T>(
     std::iter::from_fn(move || {
         if let Some((g, m)) = next.clone() {
             next = next_concept(context, &m);
-            return Some((g, m));
+            Some((g, m))
         } else {
-            return None;
+            None
+        }
+    })
+}
+
+// Counts the concepts lectically smaller than the closure of the intent, only one concept is kept at a time
+pub fn lectic_rank<T>(context: &FormalContext<T>, intent: &BitSet) -> usize {
+    let intent = context.index_attribute_hull(intent);
+    concepts(context)
+        .position(|(_, other)| other == intent)
+        .unwrap()
+}
+
+// Advances the lectic walk n steps, so no concepts before the n-th one are kept
+pub fn nth_concept<T>(context: &FormalContext<T>, n: usize) -> Option<(BitSet, BitSet)> {
+    concepts(context).nth(n)
+}
+
+fn next_object_concept<T>(context: &FormalContext<T>, a: &BitSet) -> Option<(BitSet, BitSet)> {
+    let mut a_new = a.clone();
+    for i in (0..context.objects.len()).rev() {
+        if a_new.contains(i) {
+            a_new.remove(i);
+        } else {
+            let mut b = a_new.clone();
+            b.insert(i);
+            let ms = context.index_object_derivation(&b);
+            b = context.index_attribute_derivation(&ms);
+            if b.difference(&a_new).next().unwrap() >= i {
+                return Some((b, ms));
+            }
+        }
+    }
+
+    None
+}
+
+// Walks the closed object sets in lectic order, the dual of concepts
+pub fn object_concepts<'a, T>(
+    context: &'a FormalContext<T>,
+) -> impl Iterator<Item = (BitSet, BitSet)> + 'a {
+    let ms = context.index_object_derivation(&BitSet::new());
+    let gs = context.index_attribute_derivation(&ms);
+    let mut next = Some((gs, ms));
+    std::iter::from_fn(move || {
+        if let Some((g, m)) = next.clone() {
+            next = next_object_concept(context, &g);
+            Some((g, m))
+        } else {
+            None
         }
     })
 }
@@ -52,7 +109,13 @@ mod tests {
     use bit_set::BitSet;
     use itertools::Itertools;
 
-    use crate::{algorithms::next_closure::concepts, FormalContext};
+    use crate::{
+        algorithms::next_closure::{
+            concepts, lectic_rank, next_concept_from, nth_concept, object_concepts,
+        },
+        util::bitset_from_indices,
+        FormalContext,
+    };
 
     #[test]
     fn test_concepts() {
@@ -72,4 +135,134 @@ mod tests {
         }
         assert_eq!(concepts, concepts_val);
     }
+
+    #[test]
+    fn test_object_concepts() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+        ];
+        for (path, concept_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            let object_concepts: Vec<_> = object_concepts(&context).collect();
+            let concepts_set: BTreeSet<_> = concepts(&context).collect();
+            let object_concepts_set: BTreeSet<_> = object_concepts.iter().cloned().collect();
+
+            assert_eq!(object_concepts.len(), concept_count, "{path}");
+            assert_eq!(object_concepts_set.len(), concept_count, "{path}");
+            assert_eq!(object_concepts_set, concepts_set, "{path}");
+
+            // The extents are in lectic order, the smallest object telling two apart lies in the later one
+            for pair in object_concepts.windows(2) {
+                let smallest = pair[0].0.symmetric_difference(&pair[1].0).next();
+                assert!(smallest.is_some_and(|g| pair[1].0.contains(g)), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_object_concepts_order() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        // The extents in lectic order of the objects
+        let extents: Vec<BitSet> = object_concepts(&context).map(|(g, _)| g).collect();
+        assert_eq!(
+            extents,
+            vec![
+                BitSet::new(),
+                BitSet::from_iter([3]),
+                BitSet::from_iter([3, 5]),
+                BitSet::from_iter([2, 3, 5]),
+                BitSet::from_iter([1]),
+                BitSet::from_iter([1, 6]),
+                BitSet::from_iter([0]),
+                BitSet::from_iter([0, 4]),
+                BitSet::from_iter([0, 1, 3, 5]),
+                BitSet::from_iter([0, 1, 2, 3, 4, 5, 6]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_next_concept_from() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let all_concepts: Vec<_> = concepts(&context).collect();
+        let middle = all_concepts.len() / 2;
+
+        let mut resumed = Vec::new();
+        let mut next = next_concept_from(&context, &all_concepts[middle].1);
+        while let Some((extent, intent)) = next {
+            next = next_concept_from(&context, &intent);
+            resumed.push((extent, intent));
+        }
+        assert_eq!(resumed, all_concepts[middle + 1..]);
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let all_concepts: Vec<_> = concepts(&context).collect();
+        for (index, (_, intent)) in all_concepts.iter().enumerate() {
+            for m in 0..context.attributes.len() {
+                let mut unclosed = intent.clone();
+                unclosed.insert(m);
+                let closed = context.index_attribute_hull(&unclosed);
+                let position = all_concepts.iter().position(|(_, x)| *x == closed).unwrap();
+                assert!(position >= index);
+                assert_eq!(
+                    next_concept_from(&context, &unclosed),
+                    all_concepts.get(position + 1).cloned()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lectic_rank() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let all_concepts: Vec<_> = concepts(&context).collect();
+
+        assert_eq!(
+            lectic_rank(&context, &context.empty_attribute_set_concept().1),
+            0
+        );
+        assert_eq!(
+            lectic_rank(&context, &context.full_attribute_set_concept().1),
+            all_concepts.len() - 1
+        );
+
+        let middle = all_concepts.len() / 2;
+        assert_eq!(lectic_rank(&context, &all_concepts[middle].1), middle);
+        // {0} is not closed, its closure is {0, 1, 2}
+        assert_eq!(
+            lectic_rank(&context, &bitset_from_indices(&[0])),
+            lectic_rank(&context, &bitset_from_indices(&[0, 1, 2]))
+        );
+    }
+
+    #[test]
+    fn test_nth_concept() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let count = concepts(&context).count();
+
+        assert_eq!(
+            nth_concept(&context, 0),
+            Some(context.empty_attribute_set_concept())
+        );
+        assert_eq!(
+            nth_concept(&context, count - 1),
+            Some(context.full_attribute_set_concept())
+        );
+        assert_eq!(nth_concept(&context, count), None);
+        for n in 0..count {
+            assert_eq!(
+                lectic_rank(&context, &nth_concept(&context, n).unwrap().1),
+                n
+            );
+        }
+    }
 }
@@ -0,0 +1,247 @@
+use bit_set::BitSet;
+
+use crate::FormalContext;
+
+// Computes the minimal sets intersecting every given edge, following Berge's algorithm
+pub(crate) fn minimal_transversals(edges: &[BitSet]) -> Vec<BitSet> {
+    let mut transversals = vec![BitSet::new()];
+    for edge in edges {
+        let mut next: Vec<BitSet> = Vec::new();
+        for transversal in &transversals {
+            if transversal.is_disjoint(edge) {
+                for n in edge {
+                    let mut extended = transversal.clone();
+                    extended.insert(n);
+                    next.push(extended);
+                }
+            } else {
+                next.push(transversal.clone());
+            }
+        }
+        next.sort_by_key(|x| x.len());
+        transversals.clear();
+        for candidate in next {
+            if !transversals.iter().any(|x| x.is_subset(&candidate)) {
+                transversals.push(candidate);
+            }
+        }
+    }
+    transversals
+}
+
+// The proper premises of an attribute are the minimal attribute sets not containing it whose closure contains it
+// These are the minimal transversals of the complements of the object intents lacking the attribute
+pub fn proper_premises<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
+    let all_attributes = context.all_attributes();
+    let mut implications: Vec<(BitSet, BitSet)> = Vec::new();
+
+    for m in 0..context.attributes.len() {
+        let edges: Vec<BitSet> = context
+            .atomic_object_derivations
+            .iter()
+            .filter(|intent| !intent.contains(m))
+            .map(|intent| {
+                let mut edge: BitSet = all_attributes.difference(intent).collect();
+                edge.remove(m);
+                edge
+            })
+            .collect();
+
+        if edges.iter().any(|edge| edge.is_empty()) {
+            continue;
+        }
+
+        for premise in minimal_transversals(&edges) {
+            match implications.iter_mut().find(|(p, _)| *p == premise) {
+                Some((_, conclusion)) => {
+                    conclusion.insert(m);
+                }
+                None => {
+                    let mut conclusion = BitSet::new();
+                    conclusion.insert(m);
+                    implications.push((premise, conclusion));
+                }
+            }
+        }
+    }
+    implications
+}
+
+// The implications from the proper premises form the canonical direct basis, a closure is computed
+// by applying every implication once
+pub fn canonical_direct_basis<T>(context: &FormalContext<T>) -> Vec<(BitSet, BitSet)> {
+    proper_premises(context)
+}
+
+// Applies every implication of a direct basis once, without iterating to a fixpoint
+pub fn direct_closure(basis: &[(BitSet, BitSet)], input: &BitSet) -> BitSet {
+    let mut output = input.clone();
+    for (premise, conclusion) in basis {
+        if premise.is_subset(input) {
+            output.union_with(conclusion);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+    use itertools::Itertools;
+
+    use crate::{
+        algorithms::{
+            canonical_basis::entails,
+            proper_premises::{canonical_direct_basis, direct_closure, proper_premises},
+        },
+        util::bitset_from_indices,
+        FormalContext,
+    };
+
+    #[test]
+    fn test_proper_premises() {
+        type Implications = &'static [(&'static [usize], &'static [usize])];
+        // Every living being needs water, so the empty set is the only proper premise of attribute 0
+        let fixtures: [(&str, Implications); 4] = [
+            (
+                "test_data/triangles.cxt",
+                &[
+                    (&[0], &[1, 2]),
+                    (&[0, 3], &[4]),
+                    (&[0, 4], &[3]),
+                    (&[2, 3], &[0, 1, 4]),
+                    (&[2, 4], &[0, 1, 3]),
+                    (&[3, 4], &[0, 1, 2]),
+                ],
+            ),
+            (
+                "test_data/living_beings_and_water.cxt",
+                &[
+                    (&[], &[0]),
+                    (&[1, 3], &[5]),
+                    (&[1, 4], &[5, 6, 7, 8]),
+                    (&[1, 8], &[3, 4, 5]),
+                    (&[2, 6], &[7]),
+                    (&[3, 6], &[1, 2, 4, 5, 7, 8]),
+                    (&[3, 7], &[1, 2, 4, 5, 8]),
+                    (&[3, 8], &[1, 4, 5]),
+                    (&[4], &[2, 3]),
+                    (&[4, 5], &[1, 6, 7, 8]),
+                    (&[4, 6], &[1, 5, 7, 8]),
+                    (&[4, 7], &[1, 5, 8]),
+                    (&[4, 8], &[1, 5]),
+                    (&[5], &[3]),
+                    (&[5, 6], &[1, 2, 4, 7, 8]),
+                    (&[5, 7], &[1, 2, 4, 8]),
+                    (&[5, 8], &[1, 4]),
+                    (&[7], &[6]),
+                    (&[8], &[2, 6, 7]),
+                ],
+            ),
+            (
+                "test_data/eu.cxt",
+                &[
+                    (&[0], &[5, 6]),
+                    (&[0, 1, 3], &[2]),
+                    (&[0, 4], &[1, 3]),
+                    (&[1, 2], &[0]),
+                    (&[1, 4], &[0, 3]),
+                    (&[1, 5], &[6]),
+                    (&[2], &[5, 6]),
+                    (&[3], &[5, 6]),
+                    (&[4], &[2, 5, 6]),
+                    (&[6], &[5]),
+                ],
+            ),
+            (
+                "test_data/data_from_paper.cxt",
+                &[
+                    (&[2, 4], &[3, 5]),
+                    (&[3], &[0, 2, 4, 5]),
+                    (&[4], &[0]),
+                    (&[5], &[0, 2, 3, 4]),
+                ],
+            ),
+        ];
+        for (path, expected) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            let proper_premises = proper_premises(&context);
+            let canonical_basis = context.canonical_basis();
+
+            assert_eq!(proper_premises.len(), expected.len(), "{path}");
+            for (premise, conclusion) in expected {
+                let implication: (BitSet, BitSet) = (
+                    premise.iter().copied().collect(),
+                    conclusion.iter().copied().collect(),
+                );
+                assert!(proper_premises.contains(&implication), "{path}");
+            }
+            for (premise, conclusion) in &proper_premises {
+                assert!(entails(&canonical_basis, (premise, conclusion)), "{path}");
+            }
+            for (premise, conclusion) in &canonical_basis {
+                assert!(entails(&proper_premises, (premise, conclusion)), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_direct_closure() {
+        // Triangles: one pass of the canonical basis only applies {0} -> {0,1,2}, the direct basis also has
+        // {0,3} -> {4}
+        let fixtures = [
+            (
+                "test_data/triangles.cxt",
+                vec![0, 3],
+                vec![0, 1, 2, 3],
+                vec![0, 1, 2, 3, 4],
+            ),
+            (
+                "test_data/living_beings_and_water.cxt",
+                vec![4],
+                vec![0, 4],
+                vec![0, 2, 3, 4],
+            ),
+            (
+                "test_data/eu.cxt",
+                vec![0, 4],
+                vec![0, 2, 4, 5, 6],
+                vec![0, 1, 2, 3, 4, 5, 6],
+            ),
+            (
+                "test_data/data_from_paper.cxt",
+                vec![2, 4],
+                vec![0, 2, 4],
+                vec![0, 2, 3, 4, 5],
+            ),
+        ];
+        for (path, input, one_pass, closure) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let basis = canonical_direct_basis(&context);
+
+            for subset in (0..context.attributes.len()).powerset() {
+                let subset: BitSet = subset.into_iter().collect();
+                assert_eq!(
+                    direct_closure(&basis, &subset),
+                    context.index_attribute_hull(&subset),
+                    "{path}"
+                );
+            }
+
+            let input = bitset_from_indices(&input);
+            assert_eq!(
+                direct_closure(&context.canonical_basis(), &input),
+                bitset_from_indices(&one_pass),
+                "{path}"
+            );
+            assert_eq!(
+                direct_closure(&basis, &input),
+                bitset_from_indices(&closure),
+                "{path}"
+            );
+        }
+    }
+}
@@ -0,0 +1,189 @@
+//! Random sampling in concept lattices.
+//!
+//! Sampling is not gated behind a `rand` feature and does not use the `rand` crate. The random numbers come from the
+//! small SplitMix64 generator below instead, so sampling is always available without a new dependency, and a seed
+//! gives the same samples on every platform and with every version of the crate.
+
+use bit_set::BitSet;
+use std::collections::HashMap;
+
+use super::upper_neighbor::{lower_covers, upper_covers};
+use crate::FormalContext;
+
+// The SplitMix64 generator, its statistical quality is enough for sampling
+pub(crate) struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub(crate) fn new(seed: u64) -> Self {
+        Random { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Draws a number below the bound, the bias of the modulo is negligible for small bounds
+    pub(crate) fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    // Keeps every element of the set with probability one half
+    pub(crate) fn subset(&mut self, set: &BitSet) -> BitSet {
+        set.iter().filter(|_| self.next_u64() & 1 == 1).collect()
+    }
+}
+
+fn neighbors<T>(context: &FormalContext<T>, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+    let mut neighbors = upper_covers(context, intent);
+    neighbors.extend(lower_covers(context, intent));
+    neighbors
+}
+
+// Draws concepts by a lazy random walk on the covering graph, starting at the top concept.
+// A step proposes a random neighbor and moves there with probability min(1, deg(current) / deg(neighbor)),
+// which makes the uniform distribution stationary. The walk waits one step in two so it does not alternate,
+// and it takes a number of steps proportional to the size of the context before each sample, so the samples
+// are only close to uniform if the walk mixes within that many steps
+pub fn sample_concepts<T>(
+    context: &FormalContext<T>,
+    n: usize,
+    seed: u64,
+) -> Vec<(BitSet, BitSet)> {
+    let mut random = Random::new(seed);
+    let steps = 4 * (context.objects.len() + context.attributes.len()).max(1);
+
+    // The neighbors are cached by intent, the walk visits the same concepts many times
+    let mut cache: HashMap<BitSet, Vec<(BitSet, BitSet)>> = HashMap::new();
+    let extent = context.index_attribute_derivation(&BitSet::new());
+    let mut current = (extent.clone(), context.index_object_derivation(&extent));
+    let mut current_neighbors = neighbors(context, &current.1);
+
+    let mut samples = Vec::with_capacity(n);
+    for _ in 0..n {
+        for _ in 0..steps {
+            if current_neighbors.is_empty() || random.next_u64() & 1 == 0 {
+                continue;
+            }
+            let proposal = &current_neighbors[random.below(current_neighbors.len())];
+            let proposal_neighbors = cache
+                .entry(proposal.1.clone())
+                .or_insert_with(|| neighbors(context, &proposal.1));
+            if random.below(proposal_neighbors.len()) < current_neighbors.len() {
+                current = proposal.clone();
+                current_neighbors = proposal_neighbors.clone();
+            }
+        }
+        samples.push(current.clone());
+    }
+    samples
+}
+
+// Estimates the stability, the share of subsets of the extent whose derivation is the intent,
+// from the given number of random subsets
+// Panics if samples is zero, since no subset gives no estimate
+pub fn approximate_stability<T>(
+    context: &FormalContext<T>,
+    concept: &(BitSet, BitSet),
+    samples: usize,
+    seed: u64,
+) -> f64 {
+    assert!(
+        samples > 0,
+        "approximate_stability needs at least one sample"
+    );
+    let mut random = Random::new(seed);
+    let hits = (0..samples)
+        .filter(|_| context.index_object_derivation(&random.subset(&concept.0)) == concept.1)
+        .count();
+    hits as f64 / samples as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use bit_set::BitSet;
+    use std::{collections::HashMap, fs};
+
+    use crate::{
+        algorithms::sampling::{approximate_stability, sample_concepts},
+        FormalContext,
+    };
+
+    #[test]
+    fn test_sample_concepts() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+            ("test_data/data_from_paper.cxt", 12),
+        ];
+        for (path, concept_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let samples = sample_concepts(&context, 50, 7);
+
+            assert_eq!(samples.len(), 50, "{path}");
+            for (extent, intent) in &samples {
+                assert_eq!(context.index_object_derivation(extent), *intent, "{path}");
+                assert_eq!(
+                    context.index_attribute_derivation(intent),
+                    *extent,
+                    "{path}"
+                );
+            }
+            assert_eq!(samples, sample_concepts(&context, 50, 7), "{path}");
+
+            // Each concept should be drawn about 100 times, the top and the bottom included
+            let samples = sample_concepts(&context, 100 * concept_count, 11);
+            let mut counts: HashMap<BitSet, usize> = HashMap::new();
+            for (_, intent) in samples {
+                *counts.entry(intent).or_insert(0) += 1;
+            }
+            assert_eq!(counts.len(), concept_count, "{path}");
+            for count in counts.values() {
+                assert!((60..140).contains(count), "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_approximate_stability() {
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+
+        for concept in context.fcbo_index_concepts() {
+            if concept.0.len() > 6 {
+                continue;
+            }
+            let extent: Vec<usize> = concept.0.iter().collect();
+            let subsets = 1 << extent.len();
+            let stable = (0..subsets)
+                .filter(|bits: &usize| {
+                    let subset: BitSet = (0..extent.len())
+                        .filter(|i| bits & (1 << i) != 0)
+                        .map(|i| extent[i])
+                        .collect();
+                    context.index_object_derivation(&subset) == concept.1
+                })
+                .count();
+            let exact = stable as f64 / subsets as f64;
+
+            assert!((approximate_stability(&context, &concept, 20000, 3) - exact).abs() < 0.02);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one sample")]
+    fn test_approximate_stability_without_samples() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let top = context.empty_attribute_set_concept();
+        approximate_stability(&context, &top, 0, 3);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::FormalContext;
+
+use super::fcbo;
+
+// Counts the concepts by the size of their extent, index k holds the number of concepts with k objects
+pub fn extent_size_histogram<T>(context: &FormalContext<T>) -> Vec<usize> {
+    let mut histogram = vec![0; context.objects.len() + 1];
+    for (extent, _) in fcbo::fcbo_concepts(context) {
+        histogram[extent.len()] += 1;
+    }
+    histogram
+}
+
+// Returns the mean and the maximum size of the intents, the concepts are not stored
+pub fn intent_size_stats<T>(context: &FormalContext<T>) -> (f64, usize) {
+    let mut count = 0;
+    let mut sum = 0;
+    let mut max = 0;
+    for (_, intent) in fcbo::fcbo_concepts(context) {
+        count += 1;
+        sum += intent.len();
+        max = max.max(intent.len());
+    }
+    (sum as f64 / count as f64, max)
+}
+
+// Toggles every cell of the table and counts the concepts of the changed context, the k cells changing the count
+// the most are returned with the change. This enumerates the concepts once per cell, so it is only feasible for small contexts
+pub fn influential_incidences<T>(
+    context: &FormalContext<T>,
+    top_k: usize,
+) -> Vec<((usize, usize), isize)> {
+    let count = fcbo::fcbo_concepts(context).count() as isize;
+
+    let mut changes: Vec<((usize, usize), isize)> = Vec::new();
+    for g in 0..context.objects.len() {
+        for m in 0..context.attributes.len() {
+            let mut incidence = context.incidence.clone();
+            if !incidence.remove(&(g, m)) {
+                incidence.insert((g, m));
+            }
+            let toggled = FormalContext::construct(
+                (0..context.objects.len()).collect(),
+                (0..context.attributes.len()).collect(),
+                incidence,
+            );
+            changes.push((
+                (g, m),
+                fcbo::fcbo_concepts(&toggled).count() as isize - count,
+            ));
+        }
+    }
+
+    // The sort is stable, so cells with the same change stay in the order of the table
+    changes.sort_by_key(|(_, change)| std::cmp::Reverse(change.abs()));
+    changes.truncate(top_k);
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{
+        algorithms::statistics::{
+            extent_size_histogram, influential_incidences, intent_size_stats,
+        },
+        FormalContext,
+    };
+
+    #[test]
+    fn test_extent_size_histogram() {
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+
+        let histogram = extent_size_histogram(&context);
+
+        assert_eq!(histogram.len(), context.objects.len() + 1);
+        assert_eq!(
+            histogram.iter().sum::<usize>(),
+            context.fcbo_index_concepts().count()
+        );
+        assert_eq!(histogram[context.objects.len()], 1);
+    }
+
+    #[test]
+    fn test_intent_size_stats() {
+        let fixtures = [
+            // The empty top intent, four intents of size 1, three of size 2, one of size 3 and the bottom intent of
+            // all 5 attributes
+            ("test_data/triangles.cxt", 18, 10, 5),
+            // The top intent {0}, four intents of size 2, five of size 3, five of size 4, three of size 5 and the
+            // bottom intent of all 9 attributes
+            ("test_data/living_beings_and_water.cxt", 68, 19, 9),
+            // The empty top intent, two intents of size 1, one of size 2, four of size 3, six of size 4, three of
+            // size 5, one of size 6 and the bottom intent of all 7 attributes
+            ("test_data/eu.cxt", 68, 19, 7),
+        ];
+
+        for (path, size_sum, concept_count, expected_max) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let (mean, max) = intent_size_stats(&context);
+
+            assert_eq!(
+                context.fcbo_index_concepts().count(),
+                concept_count,
+                "{path}"
+            );
+            assert!(
+                (mean - size_sum as f64 / concept_count as f64).abs() < 1e-9,
+                "{path}"
+            );
+            assert_eq!(max, expected_max, "{path}");
+            assert_eq!(max, context.attributes.len(), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_influential_incidences() {
+        let context =
+            FormalContext::<String>::from(b"B\n\n3\n3\n\na\nb\nc\nx\ny\nz\nXXX\nXX.\nX.X\n")
+                .unwrap();
+        let influential = influential_incidences(&context, 3);
+
+        // Without the cross of a and x the rows are the complements of the diagonal, which have all 8 concepts
+        assert_eq!(influential.len(), 3);
+        assert_eq!(influential[0], ((0, 0), 4));
+        assert_eq!(influential[1].1, -2);
+        assert_eq!(influential[2].1, -2);
+        assert_eq!(influential_incidences(&context, 20).len(), 9);
+    }
+}
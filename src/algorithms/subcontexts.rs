@@ -0,0 +1,110 @@
+use bit_set::BitSet;
+
+use crate::FormalContext;
+
+use super::canonical_basis::{implication_closure, next_implication_closed_set};
+
+// Every concept (A, B) has to restrict to the concept (A ∩ H, B ∩ N) of the subcontext on H and N
+fn is_compatible<T>(context: &FormalContext<T>, objects: &BitSet, attributes: &BitSet) -> bool {
+    context.fcbo_index_concepts().all(|(extent, intent)| {
+        let extent: BitSet = extent.intersection(objects).collect();
+        let intent: BitSet = intent.intersection(attributes).collect();
+        context
+            .index_object_derivation(&extent)
+            .intersection(attributes)
+            .collect::<BitSet>()
+            == intent
+            && context
+                .index_attribute_derivation(&intent)
+                .intersection(objects)
+                .collect::<BitSet>()
+                == extent
+    })
+}
+
+// Compatible subcontexts are closed under the arrow relations, an object has to bring along the attributes
+// it has an up arrow to and an attribute the objects with a down arrow to it
+// The arrow closed subcontexts are walked in lectic order, with the objects numbered before the attributes,
+// and at most max compatible subcontexts are returned, since there can be exponentially many
+pub fn compatible_subcontexts<T: Clone>(
+    context: &FormalContext<T>,
+    max: usize,
+) -> Vec<FormalContext<T>> {
+    let object_count = context.objects.len();
+    let element_count = object_count + context.attributes.len();
+
+    let mut implications: Vec<(BitSet, BitSet)> = Vec::new();
+    for (g, m) in context.up_arrows() {
+        implications.push((
+            [g].into_iter().collect(),
+            [object_count + m].into_iter().collect(),
+        ));
+    }
+    for (g, m) in context.down_arrows() {
+        implications.push((
+            [object_count + m].into_iter().collect(),
+            [g].into_iter().collect(),
+        ));
+    }
+
+    let all_elements: BitSet = (0..element_count).collect();
+    let mut subcontexts = Vec::new();
+    let mut closed = implication_closure(&implications, &BitSet::new());
+    loop {
+        let objects: BitSet = closed.iter().filter(|&x| x < object_count).collect();
+        let attributes: BitSet = closed
+            .iter()
+            .filter(|&x| x >= object_count)
+            .map(|x| x - object_count)
+            .collect();
+        if subcontexts.len() < max && is_compatible(context, &objects, &attributes) {
+            subcontexts.push(context.subcontext(&objects, &attributes));
+        }
+
+        if subcontexts.len() >= max || closed == all_elements {
+            break;
+        }
+        closed = next_implication_closed_set(element_count, &implications, &closed);
+    }
+    subcontexts
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+
+    use crate::{algorithms::subcontexts::compatible_subcontexts, FormalContext};
+
+    #[test]
+    fn compatible_subcontexts_test() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let intents: Vec<BitSet> = context.fcbo_index_concepts().map(|x| x.1).collect();
+
+        let subcontexts = compatible_subcontexts(&context, usize::MAX);
+        assert!(subcontexts.len() > 2);
+        assert_eq!(compatible_subcontexts(&context, 2).len(), 2);
+
+        for subcontext in &subcontexts {
+            let attributes: Vec<usize> = subcontext
+                .attributes
+                .iter()
+                .map(|name| context.attributes.iter().position(|x| x == name).unwrap())
+                .collect();
+            let kept: BitSet = attributes.iter().copied().collect();
+
+            for (_, intent) in subcontext.fcbo_index_concepts() {
+                let intent: BitSet = intent.iter().map(|m| attributes[m]).collect();
+                assert!(intents
+                    .iter()
+                    .any(|x| x.intersection(&kept).collect::<BitSet>() == intent));
+            }
+        }
+
+        let full = subcontexts.last().unwrap();
+        assert_eq!(full.objects, context.objects);
+        assert_eq!(full.attributes, context.attributes);
+    }
+}
@@ -2,11 +2,10 @@ use bit_set::BitSet;
 
 use crate::FormalContext;
 
+use super::fcbo;
+
 pub fn upper_neighbor<T>(input: &BitSet, context: &FormalContext<T>) -> BitSet {
-    let diff_set: BitSet = (0..context.objects.len())
-        .collect::<BitSet>()
-        .difference(input)
-        .collect();
+    let diff_set: BitSet = context.all_objects().difference(input).collect();
 
     let mut output = diff_set.clone();
 
@@ -25,3 +24,217 @@ pub fn upper_neighbor<T>(input: &BitSet, context: &FormalContext<T>) -> BitSet {
     }
     output
 }
+
+// Dual of upper_neighbor, returns the attributes generating the lower neighbors of the intent
+pub fn lower_neighbor<T>(input: &BitSet, context: &FormalContext<T>) -> BitSet {
+    let diff_set: BitSet = context.all_attributes().difference(input).collect();
+
+    let mut output = diff_set.clone();
+
+    for m in &diff_set {
+        let mut set_m = BitSet::new();
+        set_m.insert(m);
+
+        let hull_input_m = context.index_attribute_hull(&input.union(&set_m).collect());
+
+        if hull_input_m.intersection(&output).collect::<BitSet>() != set_m {
+            output.difference_with(&set_m);
+        }
+    }
+    output
+}
+
+// Computes the upper covers of a single concept, without enumerating the other concepts
+pub fn upper_covers<T>(context: &FormalContext<T>, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+    let extent = context.index_attribute_derivation(intent);
+    let mut covers: Vec<(BitSet, BitSet)> = Vec::new();
+    for g in &upper_neighbor(&extent, context) {
+        let mut generator = extent.clone();
+        generator.insert(g);
+        let cover_extent = context.index_object_hull(&generator);
+        if !covers.iter().any(|(other, _)| *other == cover_extent) {
+            let cover_intent = context.index_object_derivation(&cover_extent);
+            covers.push((cover_extent, cover_intent));
+        }
+    }
+    covers
+}
+
+// Computes the lower covers of a single concept, without enumerating the other concepts
+pub fn lower_covers<T>(context: &FormalContext<T>, intent: &BitSet) -> Vec<(BitSet, BitSet)> {
+    let intent = context.index_attribute_hull(intent);
+    let mut covers: Vec<(BitSet, BitSet)> = Vec::new();
+    for m in &lower_neighbor(&intent, context) {
+        let mut generator = intent.clone();
+        generator.insert(m);
+        let cover_intent = context.index_attribute_hull(&generator);
+        if !covers.iter().any(|(_, other)| *other == cover_intent) {
+            let cover_extent = context.index_attribute_derivation(&cover_intent);
+            covers.push((cover_extent, cover_intent));
+        }
+    }
+    covers
+}
+
+// Pairs every concept with the number of its upper and lower covers
+pub fn concepts_with_degrees<T>(
+    context: &FormalContext<T>,
+) -> Vec<((BitSet, BitSet), usize, usize)> {
+    fcbo::fcbo_concepts(context)
+        .map(|concept| {
+            let upper = upper_covers(context, &concept.1).len();
+            let lower = lower_covers(context, &concept.1).len();
+            (concept, upper, lower)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, fs};
+
+    use bit_set::BitSet;
+
+    use crate::{
+        algorithms::upper_neighbor::{concepts_with_degrees, lower_covers, upper_covers},
+        util::bitset_from_indices,
+        ConceptLattice, FormalContext,
+    };
+
+    #[test]
+    fn covers_reach_top_and_bottom() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let top = context.empty_attribute_set_concept();
+        let bottom = context.full_attribute_set_concept();
+
+        let mut concept = bottom.clone();
+        while concept != top {
+            let covers = upper_covers(&context, &concept.1);
+            assert!(!covers.is_empty());
+            concept = covers[0].clone();
+        }
+        assert!(upper_covers(&context, &top.1).is_empty());
+
+        let mut concept = top;
+        while concept != bottom {
+            let covers = lower_covers(&context, &concept.1);
+            assert!(!covers.is_empty());
+            concept = covers[0].clone();
+        }
+        assert!(lower_covers(&context, &bottom.1).is_empty());
+    }
+
+    #[test]
+    fn covers_match_lattice() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 3, 4),
+            ("test_data/living_beings_and_water.cxt", 4, 4),
+            ("test_data/eu.cxt", 2, 2),
+            ("test_data/data_from_paper.cxt", 3, 3),
+        ];
+        for (path, atom_count, coatom_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+
+            for (index, (_, intent)) in lattice.concepts.iter().enumerate() {
+                let uppers: HashSet<_> = upper_covers(&context, intent).into_iter().collect();
+                let expected: HashSet<_> = lattice
+                    .covering
+                    .iter()
+                    .filter(|(lower, _)| *lower == index)
+                    .map(|(_, upper)| lattice.concepts[*upper].clone())
+                    .collect();
+                assert_eq!(uppers, expected, "{path}");
+
+                let lowers: HashSet<_> = lower_covers(&context, intent).into_iter().collect();
+                let expected: HashSet<_> = lattice
+                    .covering
+                    .iter()
+                    .filter(|(_, upper)| *upper == index)
+                    .map(|(lower, _)| lattice.concepts[*lower].clone())
+                    .collect();
+                assert_eq!(lowers, expected, "{path}");
+            }
+
+            let top = context.empty_attribute_set_concept().1;
+            let bottom = context.full_attribute_set_concept().1;
+            assert_eq!(upper_covers(&context, &bottom).len(), atom_count, "{path}");
+            assert_eq!(lower_covers(&context, &top).len(), coatom_count, "{path}");
+        }
+    }
+
+    #[test]
+    fn covers_of_triangles_top_and_bottom() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        // The atoms are the object concepts of 0, 1 and 3, the coatoms the attribute concepts of 1 to 4
+        let intents = |covers: Vec<(BitSet, BitSet)>| -> HashSet<BitSet> {
+            covers.into_iter().map(|(_, intent)| intent).collect()
+        };
+        assert_eq!(
+            intents(upper_covers(
+                &context,
+                &bitset_from_indices(&[0, 1, 2, 3, 4])
+            )),
+            HashSet::from([
+                bitset_from_indices(&[1, 3]),
+                bitset_from_indices(&[1, 4]),
+                bitset_from_indices(&[0, 1, 2]),
+            ])
+        );
+        assert_eq!(
+            intents(lower_covers(&context, &BitSet::new())),
+            HashSet::from([
+                bitset_from_indices(&[1]),
+                bitset_from_indices(&[2]),
+                bitset_from_indices(&[3]),
+                bitset_from_indices(&[4]),
+            ])
+        );
+    }
+
+    #[test]
+    fn degrees_match_covering() {
+        // Triangles: 14 covering edges, the top has four lower covers and the bottom the three atoms as upper covers
+        let fixtures = [
+            ("test_data/triangles.cxt", 14, 4, 3),
+            ("test_data/living_beings_and_water.cxt", 32, 4, 4),
+            ("test_data/eu.cxt", 31, 2, 2),
+            ("test_data/data_from_paper.cxt", 20, 3, 3),
+        ];
+        for (path, edge_count, top_lower, bottom_upper) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+            let degrees = concepts_with_degrees(&context);
+
+            assert_eq!(degrees.len(), lattice.concepts.len(), "{path}");
+            for (concept, upper, lower) in &degrees {
+                let index = lattice.concept_index(concept).unwrap();
+                if index == lattice.top() {
+                    assert_eq!((*upper, *lower), (0, top_lower), "{path}");
+                }
+                if index == lattice.bottom() {
+                    assert_eq!((*upper, *lower), (bottom_upper, 0), "{path}");
+                }
+                assert_eq!(
+                    *upper,
+                    lattice.covering.iter().filter(|(l, _)| *l == index).count(),
+                    "{path}"
+                );
+                assert_eq!(
+                    *lower,
+                    lattice.covering.iter().filter(|(_, u)| *u == index).count(),
+                    "{path}"
+                );
+            }
+
+            assert_eq!(
+                degrees.iter().map(|(_, upper, _)| upper).sum::<usize>(),
+                edge_count,
+                "{path}"
+            );
+        }
+    }
+}
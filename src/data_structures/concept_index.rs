@@ -0,0 +1,57 @@
+use bit_set::BitSet;
+use std::collections::HashMap;
+
+/// Looks up the position of a concept in a list of concepts by its extent or intent.
+pub struct ConceptIndex {
+    extents: HashMap<BitSet, usize>,
+    intents: HashMap<BitSet, usize>,
+}
+
+impl ConceptIndex {
+    /// Creates a ConceptIndex for the given concepts.
+    pub fn new(concepts: &[(BitSet, BitSet)]) -> Self {
+        let mut extents = HashMap::with_capacity(concepts.len());
+        let mut intents = HashMap::with_capacity(concepts.len());
+        for (index, (extent, intent)) in concepts.iter().enumerate() {
+            extents.insert(extent.clone(), index);
+            intents.insert(intent.clone(), index);
+        }
+        ConceptIndex { extents, intents }
+    }
+
+    /// Returns the index of the concept with the given extent.
+    pub fn by_extent(&self, extent: &BitSet) -> Option<usize> {
+        self.extents.get(extent).copied()
+    }
+
+    /// Returns the index of the concept with the given intent.
+    pub fn by_intent(&self, intent: &BitSet) -> Option<usize> {
+        self.intents.get(intent).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+
+    use crate::{data_structures::concept_index::ConceptIndex, FormalContext};
+
+    #[test]
+    fn concept_lookup() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+        let index = ConceptIndex::new(&concepts);
+
+        for (position, (extent, intent)) in concepts.iter().enumerate() {
+            assert_eq!(index.by_extent(extent), Some(position));
+            assert_eq!(index.by_intent(intent), Some(position));
+        }
+
+        let mut not_an_intent = BitSet::new();
+        not_an_intent.insert(context.attributes.len());
+        assert_eq!(index.by_intent(&not_an_intent), None);
+    }
+}
@@ -0,0 +1,600 @@
+use bit_set::BitSet;
+use std::collections::{HashSet, VecDeque};
+
+use crate::{ConceptIndex, ContextKind, FormalContext};
+
+/// The concepts of a formal context together with their covering relation.
+pub struct ConceptLattice {
+    pub concepts: Vec<(BitSet, BitSet)>,
+    /// Pairs of concept indices (lower, upper) where the upper concept covers the lower one.
+    pub covering: Vec<(usize, usize)>,
+    index: ConceptIndex,
+}
+
+impl ConceptLattice {
+    /// Computes the concepts of a context and their covering relation.
+    pub fn new<T>(context: &FormalContext<T>) -> Self {
+        Self::from_concepts(context.fcbo_index_concepts().collect(), context)
+    }
+
+    /// Computes the covering relation of the given concepts, which have to be all concepts of the context.
+    pub fn from_concepts<T>(concepts: Vec<(BitSet, BitSet)>, context: &FormalContext<T>) -> Self {
+        let concept_indices = ConceptIndex::new(&concepts);
+
+        let mut covering = Vec::new();
+        // The only concept of an empty context covers nothing
+        if context.kind() != ContextKind::Empty {
+            for (lower, (extent, _)) in concepts.iter().enumerate() {
+                // Several objects can generate the same upper neighbor
+                let mut uppers: HashSet<usize> = HashSet::new();
+                for g in &context.upper_neighbor(extent) {
+                    let mut generator = extent.clone();
+                    generator.insert(g);
+                    let upper = concept_indices
+                        .by_extent(&context.index_object_hull(&generator))
+                        .unwrap();
+                    if uppers.insert(upper) {
+                        covering.push((lower, upper));
+                    }
+                }
+            }
+        }
+
+        ConceptLattice {
+            concepts,
+            covering,
+            index: concept_indices,
+        }
+    }
+
+    /// Returns the index of the top concept, which has the largest extent.
+    pub fn top(&self) -> usize {
+        (0..self.concepts.len())
+            .max_by_key(|&c| self.concepts[c].0.len())
+            .unwrap()
+    }
+
+    /// Returns the index of the bottom concept, which has the largest intent.
+    pub fn bottom(&self) -> usize {
+        (0..self.concepts.len())
+            .max_by_key(|&c| self.concepts[c].1.len())
+            .unwrap()
+    }
+
+    /// Returns the indices of the atoms, the concepts covering the bottom concept.
+    pub fn atoms(&self) -> BitSet {
+        let bottom = self.bottom();
+        self.covering
+            .iter()
+            .filter(|&&(lower, _)| lower == bottom)
+            .map(|&(_, upper)| upper)
+            .collect()
+    }
+
+    /// Returns the index of the given concept.
+    pub fn concept_index(&self, concept: &(BitSet, BitSet)) -> Option<usize> {
+        self.index.by_extent(&concept.0)
+    }
+
+    /// Returns the index of the smallest concept above both concepts, its intent is the intersection of their intents.
+    pub fn concept_join(&self, a: usize, b: usize) -> usize {
+        let intent: BitSet = self.concepts[a]
+            .1
+            .intersection(&self.concepts[b].1)
+            .collect();
+        self.index.by_intent(&intent).unwrap()
+    }
+
+    /// Returns the index of the largest concept below both concepts, its extent is the intersection of their extents.
+    pub fn concept_meet(&self, a: usize, b: usize) -> usize {
+        let extent: BitSet = self.concepts[a]
+            .0
+            .intersection(&self.concepts[b].0)
+            .collect();
+        self.index.by_extent(&extent).unwrap()
+    }
+
+    /// Returns the concepts which have a complement, whose join with them is the top and whose meet is the bottom concept.
+    pub fn complemented_elements(&self) -> BitSet {
+        let (top, bottom) = (self.top(), self.bottom());
+        (0..self.concepts.len())
+            .filter(|&a| {
+                (0..self.concepts.len())
+                    .any(|b| self.concept_join(a, b) == top && self.concept_meet(a, b) == bottom)
+            })
+            .collect()
+    }
+
+    /// Checks if every concept has a complement.
+    pub fn is_complemented_lattice(&self) -> bool {
+        self.complemented_elements().len() == self.concepts.len()
+    }
+
+    /// Returns the number of concepts in the longest chain from the bottom to the top concept.
+    pub fn height(&self) -> usize {
+        // Every upper concept has a larger extent, so sorting by extent size visits lower concepts first
+        let mut order: Vec<usize> = (0..self.concepts.len()).collect();
+        order.sort_by_key(|&index| self.concepts[index].0.len());
+
+        let mut uppers: Vec<Vec<usize>> = vec![Vec::new(); self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            uppers[lower].push(upper);
+        }
+
+        let mut chain_lengths = vec![1; self.concepts.len()];
+        for index in order {
+            for &upper in &uppers[index] {
+                chain_lengths[upper] = chain_lengths[upper].max(chain_lengths[index] + 1);
+            }
+        }
+        chain_lengths.into_iter().max().unwrap_or(0)
+    }
+
+    /// Groups the concepts by the length of the longest path from the top concept down to them.
+    pub fn levels(&self) -> Vec<Vec<usize>> {
+        // Every lower concept has a smaller extent, so sorting by descending extent size visits upper concepts first
+        let mut order: Vec<usize> = (0..self.concepts.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.concepts[index].0.len()));
+
+        let mut lowers: Vec<Vec<usize>> = vec![Vec::new(); self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            lowers[upper].push(lower);
+        }
+
+        let mut depths = vec![0; self.concepts.len()];
+        for &index in &order {
+            for &lower in &lowers[index] {
+                depths[lower] = depths[lower].max(depths[index] + 1);
+            }
+        }
+
+        let mut levels: Vec<Vec<usize>> = Vec::new();
+        for index in 0..self.concepts.len() {
+            if levels.len() <= depths[index] {
+                levels.resize(depths[index] + 1, Vec::new());
+            }
+            levels[depths[index]].push(index);
+        }
+        levels
+    }
+
+    /// Counts the maximal chains, the paths from the top to the bottom concept along the covering relation.
+    pub fn maximal_chains(&self) -> usize {
+        // Every lower concept has a smaller extent, so sorting by descending extent size visits upper concepts first
+        let mut order: Vec<usize> = (0..self.concepts.len()).collect();
+        order.sort_by_key(|&index| std::cmp::Reverse(self.concepts[index].0.len()));
+
+        let mut lowers: Vec<Vec<usize>> = vec![Vec::new(); self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            lowers[upper].push(lower);
+        }
+
+        let mut paths = vec![0; self.concepts.len()];
+        paths[self.top()] = 1;
+        for &index in &order {
+            for &lower in &lowers[index] {
+                paths[lower] += paths[index];
+            }
+        }
+        paths[self.bottom()]
+    }
+
+    /// Returns the size of the largest set of pairwise incomparable concepts.
+    pub fn width(&self) -> usize {
+        // By Dilworth's theorem the width is the number of concepts minus a maximum matching
+        // in the bipartite graph of the strict order
+        let below: Vec<Vec<usize>> = self
+            .concepts
+            .iter()
+            .map(|(extent, _)| {
+                (0..self.concepts.len())
+                    .filter(|&other| {
+                        self.concepts[other].0.is_subset(extent)
+                            && &self.concepts[other].0 != extent
+                    })
+                    .collect()
+            })
+            .collect();
+
+        fn augment(
+            upper: usize,
+            below: &[Vec<usize>],
+            visited: &mut [bool],
+            matched: &mut [Option<usize>],
+        ) -> bool {
+            for &lower in &below[upper] {
+                if !visited[lower] {
+                    visited[lower] = true;
+                    if matched[lower].is_none_or(|other| augment(other, below, visited, matched)) {
+                        matched[lower] = Some(upper);
+                        return true;
+                    }
+                }
+            }
+            false
+        }
+
+        let mut matched: Vec<Option<usize>> = vec![None; self.concepts.len()];
+        let mut matching = 0;
+        for upper in 0..self.concepts.len() {
+            let mut visited = vec![false; self.concepts.len()];
+            if augment(upper, &below, &mut visited, &mut matched) {
+                matching += 1;
+            }
+        }
+        self.concepts.len() - matching
+    }
+
+    /// Creates the context of the join-irreducible concepts as objects and the meet-irreducible concepts as attributes,
+    /// named by their concept index, whose concept lattice is isomorphic to this one.
+    pub fn irreducible_context(&self) -> FormalContext<usize> {
+        let mut lower_covers = vec![0; self.concepts.len()];
+        let mut upper_covers = vec![0; self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            upper_covers[lower] += 1;
+            lower_covers[upper] += 1;
+        }
+
+        let join_irreducibles: Vec<usize> = (0..self.concepts.len())
+            .filter(|&c| lower_covers[c] == 1)
+            .collect();
+        let meet_irreducibles: Vec<usize> = (0..self.concepts.len())
+            .filter(|&c| upper_covers[c] == 1)
+            .collect();
+
+        let mut incidence = HashSet::new();
+        for (g, &j) in join_irreducibles.iter().enumerate() {
+            for (m, &n) in meet_irreducibles.iter().enumerate() {
+                if self.concepts[j].0.is_subset(&self.concepts[n].0) {
+                    incidence.insert((g, m));
+                }
+            }
+        }
+
+        FormalContext::construct(join_irreducibles, meet_irreducibles, incidence)
+    }
+
+    /// Returns a spanning tree of the covering relation rooted at the top concept, as pairs (lower, upper) like the covering.
+    /// Every concept except the top is attached below its upper cover with the largest extent.
+    pub fn spanning_tree(&self) -> Vec<(usize, usize)> {
+        let mut parents: Vec<Option<usize>> = vec![None; self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            match parents[lower] {
+                Some(parent) if self.concepts[parent].0.len() >= self.concepts[upper].0.len() => {}
+                _ => parents[lower] = Some(upper),
+            }
+        }
+
+        parents
+            .iter()
+            .enumerate()
+            .filter_map(|(lower, parent)| parent.map(|upper| (lower, upper)))
+            .collect()
+    }
+
+    /// Returns the length of a shortest path between two concepts in the covering relation taken as undirected graph,
+    /// or `None` if there is no such path.
+    pub fn distance(&self, a: usize, b: usize) -> Option<usize> {
+        let mut neighbors: Vec<Vec<usize>> = vec![Vec::new(); self.concepts.len()];
+        for &(lower, upper) in &self.covering {
+            neighbors[lower].push(upper);
+            neighbors[upper].push(lower);
+        }
+
+        let mut distances: Vec<Option<usize>> = vec![None; self.concepts.len()];
+        distances[a] = Some(0);
+        let mut queue = VecDeque::from([a]);
+        while let Some(current) = queue.pop_front() {
+            if current == b {
+                return distances[b];
+            }
+            for &next in &neighbors[current] {
+                if distances[next].is_none() {
+                    distances[next] = distances[current].map(|d| d + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Writes the covering relation as one line of lower and upper concept index per covering pair.
+    pub fn to_edge_list(&self) -> String {
+        self.covering
+            .iter()
+            .map(|(lower, upper)| format!("{} {}\n", lower, upper))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+
+    use crate::{
+        algorithms::isomorphism::lattice_isomorphic,
+        data_structures::concept_lattice::ConceptLattice, util::bitset_from_indices, FormalContext,
+    };
+
+    #[test]
+    fn covering_relation() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        let mut covering = lattice.covering.clone();
+        covering.sort();
+        assert_eq!(
+            covering,
+            vec![
+                (1, 6),
+                (2, 0),
+                (3, 0),
+                (4, 0),
+                (5, 0),
+                (6, 2),
+                (6, 3),
+                (7, 2),
+                (7, 4),
+                (8, 2),
+                (8, 5),
+                (9, 1),
+                (9, 7),
+                (9, 8)
+            ]
+        );
+    }
+
+    #[test]
+    fn lattice_of_empty_context() {
+        let context = FormalContext::<String>::from(b"B\n\n0\n2\n\na\nb\n").unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(
+            lattice.concepts,
+            vec![(BitSet::new(), bitset_from_indices(&[0, 1]))]
+        );
+        assert!(lattice.covering.is_empty());
+        assert_eq!(lattice.top(), lattice.bottom());
+    }
+
+    #[test]
+    fn height_and_width() {
+        let context = FormalContext::<String>::from(
+            b"B\n\n4\n3\n\na\nb\nc\nd\nx\ny\nz\nXXX\nXX.\nX..\n...\n",
+        )
+        .unwrap();
+        let lattice = ConceptLattice::new(&context);
+        let max_intent = lattice.concepts.iter().map(|x| x.1.len()).max().unwrap();
+
+        assert_eq!(lattice.height(), max_intent + 1);
+        assert_eq!(lattice.width(), 1);
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(lattice.height(), 5);
+        assert_eq!(lattice.width(), 4);
+    }
+
+    #[test]
+    fn edge_list() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+        let edge_list = lattice.to_edge_list();
+
+        assert_eq!(edge_list.lines().count(), lattice.covering.len());
+        assert!(edge_list.lines().any(|line| line == "9 1"));
+    }
+
+    #[test]
+    fn irreducible_context() {
+        // The triangles context is reduced, so it is its own irreducible context up to renaming
+        let fixtures = [
+            ("test_data/triangles.cxt", 7, 5, 12),
+            ("test_data/living_beings_and_water.cxt", 8, 8, 26),
+            ("test_data/eu.cxt", 10, 7, 39),
+            ("test_data/data_from_paper.cxt", 4, 4, 11),
+        ];
+        for (path, object_count, attribute_count, incidence_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+            let irreducible_context = lattice.irreducible_context();
+
+            assert_eq!(
+                irreducible_context.fcbo_index_concepts().count(),
+                lattice.concepts.len(),
+                "{path}"
+            );
+            assert!(lattice_isomorphic(&context, &irreducible_context), "{path}");
+
+            assert_eq!(irreducible_context.objects.len(), object_count, "{path}");
+            assert_eq!(
+                irreducible_context.attributes.len(),
+                attribute_count,
+                "{path}"
+            );
+            assert_eq!(
+                irreducible_context.incidence.len(),
+                incidence_count,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn join_and_meet() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(lattice.top(), 0);
+        assert_eq!(lattice.bottom(), 9);
+        // {1,3} and {1,4} meet in the bottom concept and join in {1}
+        assert_eq!(lattice.concept_meet(7, 8), 9);
+        assert_eq!(lattice.concept_join(7, 8), 2);
+        for c in 0..lattice.concepts.len() {
+            assert_eq!(lattice.concept_join(c, lattice.bottom()), c);
+            assert_eq!(lattice.concept_meet(c, lattice.top()), c);
+        }
+    }
+
+    #[test]
+    fn complemented_elements() {
+        let context =
+            FormalContext::<String>::from(b"B\n\n3\n3\n\na\nb\nc\nx\ny\nz\n.XX\nX.X\nXX.\n")
+                .unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(lattice.concepts.len(), 8);
+        assert!(lattice.is_complemented_lattice());
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert!(!lattice.is_complemented_lattice());
+        assert!(lattice.complemented_elements().contains(lattice.top()));
+        assert!(lattice.complemented_elements().contains(lattice.bottom()));
+    }
+
+    #[test]
+    fn spanning_tree() {
+        // Every concept hangs below an upper cover with the largest extent, so the parent extent sizes add up
+        // to the same sum whichever of several equally large upper covers is taken
+        let fixtures = [
+            ("test_data/triangles.cxt", 43),
+            ("test_data/living_beings_and_water.cxt", 81),
+            ("test_data/eu.cxt", 580),
+            ("test_data/data_from_paper.cxt", 31),
+        ];
+        for (path, parent_extent_sizes) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+            let tree = lattice.spanning_tree();
+
+            assert_eq!(tree.len(), lattice.concepts.len() - 1, "{path}");
+            for edge in &tree {
+                assert!(lattice.covering.contains(edge), "{path}");
+            }
+            for concept in 0..lattice.concepts.len() {
+                let mut current = concept;
+                while let Some(&(_, upper)) = tree.iter().find(|(lower, _)| *lower == current) {
+                    current = upper;
+                }
+                assert_eq!(current, lattice.top(), "{path}");
+            }
+            assert_eq!(
+                tree.iter()
+                    .map(|&(_, upper)| lattice.concepts[upper].0.len())
+                    .sum::<usize>(),
+                parent_extent_sizes,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn spanning_tree_takes_larger_extents() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+        let tree = lattice.spanning_tree();
+
+        // {3,5}, {0} and {1} have two upper covers each and hang below the larger extent {0,1,3,5}
+        let parent = |extent: &[usize]| {
+            let index = lattice
+                .concepts
+                .iter()
+                .position(|(other, _)| *other == bitset_from_indices(extent))
+                .unwrap();
+            let (_, upper) = tree.iter().find(|(lower, _)| *lower == index).unwrap();
+            lattice.concepts[*upper].0.clone()
+        };
+        for extent in [&[3, 5][..], &[0], &[1]] {
+            assert_eq!(parent(extent), bitset_from_indices(&[0, 1, 3, 5]));
+        }
+        assert_eq!(parent(&[3]), bitset_from_indices(&[3, 5]));
+    }
+
+    #[test]
+    fn distance() {
+        let context = FormalContext::<String>::from(
+            b"B\n\n4\n3\n\na\nb\nc\nd\nx\ny\nz\nXXX\nXX.\nX..\n...\n",
+        )
+        .unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(
+            lattice.distance(lattice.top(), lattice.bottom()),
+            Some(lattice.height() - 1)
+        );
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(lattice.distance(3, 3), Some(0));
+        assert_eq!(lattice.distance(9, 1), Some(1));
+        // {1,3} and {1,4} are both covered by {1}
+        assert_eq!(lattice.distance(7, 8), Some(2));
+    }
+
+    #[test]
+    fn levels() {
+        // Triangles: the top, the four attribute concepts, {3,5}, {0} and {1}, then {3} and the bottom
+        let fixtures = [
+            ("test_data/triangles.cxt", vec![1, 4, 3, 1, 1]),
+            (
+                "test_data/living_beings_and_water.cxt",
+                vec![1, 4, 5, 5, 3, 1],
+            ),
+            ("test_data/eu.cxt", vec![1, 2, 1, 4, 6, 3, 1, 1]),
+            ("test_data/data_from_paper.cxt", vec![1, 3, 4, 3, 1]),
+        ];
+        for (path, level_sizes) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+            let levels = lattice.levels();
+
+            assert_eq!(levels[0], vec![lattice.top()], "{path}");
+            assert_eq!(levels.len(), lattice.height(), "{path}");
+            assert_eq!(
+                levels.iter().map(|level| level.len()).sum::<usize>(),
+                lattice.concepts.len(),
+                "{path}"
+            );
+            for &(lower, upper) in &lattice.covering {
+                let depth = |c: usize| levels.iter().position(|level| level.contains(&c)).unwrap();
+                assert!(depth(lower) > depth(upper), "{path}");
+            }
+
+            assert_eq!(
+                levels.iter().map(|level| level.len()).collect::<Vec<_>>(),
+                level_sizes,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn maximal_chains() {
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\n.X\n").unwrap();
+        assert_eq!(ConceptLattice::new(&context).maximal_chains(), 2);
+
+        let context =
+            FormalContext::<String>::from(b"B\n\n3\n3\n\na\nb\nc\nx\ny\nz\n.XX\nX.X\nXX.\n")
+                .unwrap();
+        assert_eq!(ConceptLattice::new(&context).maximal_chains(), 6);
+
+        let context = FormalContext::<String>::from(
+            b"B\n\n4\n3\n\na\nb\nc\nd\nx\ny\nz\nXXX\nXX.\nX..\n...\n",
+        )
+        .unwrap();
+        assert_eq!(ConceptLattice::new(&context).maximal_chains(), 1);
+    }
+}
@@ -0,0 +1,219 @@
+use bit_set::BitSet;
+
+use crate::{FormalContext, FormatError};
+
+/// The changes between two versions of a formal context, recorded by the names of objects and attributes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextDiff<T> {
+    pub added_objects: Vec<T>,
+    pub removed_objects: Vec<T>,
+    pub added_attributes: Vec<T>,
+    pub removed_attributes: Vec<T>,
+    /// Pairs of object and attribute names which are incident only in the newer context.
+    pub added_incidence: Vec<(T, T)>,
+    /// Pairs of object and attribute names which are incident only in the older context.
+    pub removed_incidence: Vec<(T, T)>,
+}
+
+impl<T> ContextDiff<T> {
+    /// Checks if the diff records no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added_objects.is_empty()
+            && self.removed_objects.is_empty()
+            && self.added_attributes.is_empty()
+            && self.removed_attributes.is_empty()
+            && self.added_incidence.is_empty()
+            && self.removed_incidence.is_empty()
+    }
+}
+
+// Looks up the index of every name in the other list once, so incidences can be compared by index
+fn indices_in<T: Eq>(names: &[T], other: &[T]) -> Vec<Option<usize>> {
+    names
+        .iter()
+        .map(|name| other.iter().position(|x| x == name))
+        .collect()
+}
+
+// Collects the names which have no index in the other list
+fn missing<T: Clone>(names: &[T], indices: &[Option<usize>]) -> Vec<T> {
+    names
+        .iter()
+        .zip(indices)
+        .filter(|(_, index)| index.is_none())
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+// Collects the incident pairs of the context which are not incident in the other one, sorted by index
+fn missing_incidence<T: Clone>(
+    context: &FormalContext<T>,
+    other: &FormalContext<T>,
+    object_indices: &[Option<usize>],
+    attribute_indices: &[Option<usize>],
+) -> Vec<(T, T)> {
+    let mut incidence: Vec<(usize, usize)> = context.incidence.iter().copied().collect();
+    incidence.sort();
+    incidence
+        .into_iter()
+        .filter(|&(g, m)| match (object_indices[g], attribute_indices[m]) {
+            (Some(h), Some(n)) => !other.incidence.contains(&(h, n)),
+            _ => true,
+        })
+        .map(|(g, m)| (context.objects[g].clone(), context.attributes[m].clone()))
+        .collect()
+}
+
+impl<T: Eq + Clone> FormalContext<T> {
+    /// Computes the changes leading from this context to the other one, comparing objects and attributes by name.
+    pub fn diff(&self, other: &Self) -> ContextDiff<T> {
+        let objects_in_other = indices_in(&self.objects, &other.objects);
+        let attributes_in_other = indices_in(&self.attributes, &other.attributes);
+        let objects_in_self = indices_in(&other.objects, &self.objects);
+        let attributes_in_self = indices_in(&other.attributes, &self.attributes);
+
+        ContextDiff {
+            added_objects: missing(&other.objects, &objects_in_self),
+            removed_objects: missing(&self.objects, &objects_in_other),
+            added_attributes: missing(&other.attributes, &attributes_in_self),
+            removed_attributes: missing(&self.attributes, &attributes_in_other),
+            added_incidence: missing_incidence(other, self, &objects_in_self, &attributes_in_self),
+            removed_incidence: missing_incidence(
+                self,
+                other,
+                &objects_in_other,
+                &attributes_in_other,
+            ),
+        }
+    }
+
+    /// Applies the changes of the diff by name, removed incidences, objects and attributes have to exist in this context.
+    /// Nothing is changed if the diff cannot be applied.
+    pub fn apply_diff(&mut self, diff: &ContextDiff<T>) -> Result<(), FormatError> {
+        let all_exist = diff
+            .removed_objects
+            .iter()
+            .all(|g| self.objects.contains(g))
+            && diff
+                .removed_attributes
+                .iter()
+                .all(|m| self.attributes.contains(m))
+            && diff
+                .removed_incidence
+                .iter()
+                .all(|(g, m)| self.objects.contains(g) && self.attributes.contains(m));
+        // Added incidences may refer to added objects and attributes, but not to removed ones
+        let all_added_exist = diff.added_incidence.iter().all(|(g, m)| {
+            (self.objects.contains(g) || diff.added_objects.contains(g))
+                && !diff.removed_objects.contains(g)
+                && (self.attributes.contains(m) || diff.added_attributes.contains(m))
+                && !diff.removed_attributes.contains(m)
+        });
+        if !all_exist || !all_added_exist {
+            return Err(FormatError::InvalidFormat);
+        }
+
+        for (g, m) in &diff.removed_incidence {
+            let (g, m) = (self.object_index(g), self.attribute_index(m));
+            self.incidence.remove(&(g, m));
+            self.atomic_object_derivations[g].remove(m);
+            self.atomic_attribute_derivations[m].remove(g);
+        }
+        for g in &diff.removed_objects {
+            self.remove_object(self.object_index(g));
+        }
+        for m in &diff.removed_attributes {
+            self.remove_attribute(self.attribute_index(m));
+        }
+        for m in &diff.added_attributes {
+            self.add_attribute(m.clone(), &BitSet::new());
+        }
+        for g in &diff.added_objects {
+            self.add_object(g.clone(), &BitSet::new());
+        }
+        for (g, m) in &diff.added_incidence {
+            let (g, m) = (self.object_index(g), self.attribute_index(m));
+            self.incidence.insert((g, m));
+            self.atomic_object_derivations[g].insert(m);
+            self.atomic_attribute_derivations[m].insert(g);
+        }
+        Ok(())
+    }
+
+    fn object_index(&self, name: &T) -> usize {
+        self.objects.iter().position(|g| g == name).unwrap()
+    }
+
+    fn attribute_index(&self, name: &T) -> usize {
+        self.attributes.iter().position(|m| m == name).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{util::bitset_from_indices, FormalContext};
+
+    #[test]
+    fn diff() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert!(context.diff(&context).is_empty());
+
+        let mut other = context.clone();
+        other.add_object("new".to_string(), &bitset_from_indices(&[0, 3]));
+        let diff = context.diff(&other);
+
+        assert_eq!(diff.added_objects, vec!["new".to_string()]);
+        assert!(diff.removed_objects.is_empty());
+        assert!(diff.added_attributes.is_empty() && diff.removed_attributes.is_empty());
+        assert_eq!(diff.added_incidence.len(), 2);
+        assert!(diff.removed_incidence.is_empty());
+
+        // Removing an object shifts the indices of the later ones, which must not show up as changes
+        let mut other = context.clone();
+        other.remove_object(0);
+        let diff = context.diff(&other);
+
+        assert_eq!(diff.removed_objects, vec![context.objects[0].clone()]);
+        assert_eq!(
+            diff.removed_incidence.len(),
+            context.atomic_object_derivations[0].len()
+        );
+        assert!(diff.added_incidence.is_empty());
+
+        // The same table with the attributes in the other order
+        let context = FormalContext::<String>::from(b"B\n\n1\n2\n\ng\nx\ny\nX.\n").unwrap();
+        let other = FormalContext::<String>::from(b"B\n\n1\n2\n\ng\ny\nx\n.X\n").unwrap();
+
+        assert!(context.diff(&other).is_empty());
+    }
+
+    #[test]
+    fn apply_diff() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let mut other = context.clone();
+        other.remove_object(2);
+        other.remove_attribute(0);
+        other.add_attribute("new attribute".to_string(), &bitset_from_indices(&[0, 1]));
+        other.add_object("new object".to_string(), &bitset_from_indices(&[1, 3]));
+
+        let mut patched = context.clone();
+        patched.apply_diff(&context.diff(&other)).unwrap();
+
+        assert!(patched.diff(&other).is_empty());
+        assert_eq!(
+            patched.fcbo_index_concepts().count(),
+            other.fcbo_index_concepts().count()
+        );
+
+        let mut diff = context.diff(&context);
+        diff.removed_objects.push("missing".to_string());
+
+        assert!(patched.apply_diff(&diff).is_err());
+        assert!(patched.diff(&other).is_empty());
+    }
+}
@@ -1,11 +1,15 @@
 use std::{
-    collections::HashSet,
-    io::{BufRead, Error},
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    io::{BufRead, Error, Write},
     num::ParseIntError,
 };
 
 use bit_set::{self, BitSet};
 
+use crate::ConceptLattice;
+
 #[derive(Debug)]
 pub enum FormatError {
     IoError(Error),
@@ -25,18 +29,181 @@ impl From<ParseIntError> for FormatError {
     }
 }
 
+/// The shape of a formal context, separating the degenerate cases from general contexts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContextKind {
+    /// The context has no objects or no attributes.
+    Empty,
+    /// No object has any attribute.
+    NoIncidence,
+    /// Every object has every attribute.
+    Full,
+    /// Any other context.
+    General,
+}
+
+// A set is lectically smaller if the smallest element in which the two sets differ belongs to the other set
+fn lectic_cmp(a: &BitSet, b: &BitSet) -> Ordering {
+    match a.symmetric_difference(b).next() {
+        None => Ordering::Equal,
+        Some(m) if b.contains(m) => Ordering::Less,
+        Some(_) => Ordering::Greater,
+    }
+}
+
+/// In place sorts the concepts in lectic order of their intents, for any number of attributes.
+pub fn sort_canonical(concepts: &mut [(BitSet, BitSet)]) {
+    concepts.sort_by(|a, b| lectic_cmp(&a.1, &b.1));
+}
+
+// Reads a single formal context in Burmeister format from the lines, leaving all following lines untouched
+fn read_burmeister<T, I: Iterator<Item = Result<String, Error>>, F: Fn(&str) -> T>(
+    lines: &mut I,
+    parse_name: &F,
+) -> Result<FormalContext<T>, FormatError> {
+    read_burmeister_crosses(lines, parse_name, &|x| x == 'X' || x == 'x')
+}
+
+// Reads the header and the names of the objects and attributes, leaving the lines of the table untouched
+fn read_burmeister_names<T, I: Iterator<Item = Result<String, Error>>, F: Fn(&str) -> T>(
+    lines: &mut I,
+    parse_name: &F,
+) -> Result<(Vec<T>, Vec<T>), FormatError> {
+    if lines.next().ok_or(FormatError::InvalidFormat)??.trim_end() != "B" {
+        return Err(FormatError::InvalidFormat);
+    }
+
+    lines.next().ok_or(FormatError::InvalidFormat)??;
+
+    let object_count: usize = lines
+        .next()
+        .ok_or(FormatError::InvalidFormat)??
+        .trim_end()
+        .parse()?;
+    let attribute_count: usize = lines
+        .next()
+        .ok_or(FormatError::InvalidFormat)??
+        .trim_end()
+        .parse()?;
+
+    lines.next().ok_or(FormatError::InvalidFormat)??;
+
+    let mut objects: Vec<T> = Vec::with_capacity(object_count);
+    for _ in 0..object_count {
+        let name = lines.next().ok_or(FormatError::InvalidFormat)??;
+        objects.push(parse_name(strip_carriage_return(&name)));
+    }
+
+    let mut attributes: Vec<T> = Vec::with_capacity(attribute_count);
+    for _ in 0..attribute_count {
+        let name = lines.next().ok_or(FormatError::InvalidFormat)??;
+        attributes.push(parse_name(strip_carriage_return(&name)));
+    }
+
+    Ok((objects, attributes))
+}
+
+// Like read_burmeister, with the characters of the table counted as crosses given by is_cross
+pub(crate) fn read_burmeister_crosses<
+    T,
+    I: Iterator<Item = Result<String, Error>>,
+    F: Fn(&str) -> T,
+>(
+    lines: &mut I,
+    parse_name: &F,
+    is_cross: &dyn Fn(char) -> bool,
+) -> Result<FormalContext<T>, FormatError> {
+    let (objects, attributes) = read_burmeister_names(lines, parse_name)?;
+
+    let mut incidence: HashSet<(usize, usize)> = HashSet::new();
+    for g in 0..objects.len() {
+        let line = lines.next().ok_or(FormatError::InvalidFormat)??;
+        for (m, x) in line.chars().enumerate() {
+            if is_cross(x) {
+                incidence.insert((g, m));
+            }
+        }
+    }
+
+    Ok(FormalContext::construct(objects, attributes, incidence))
+}
+
+// Like read_burmeister, but a row of the table which is missing, has the wrong length or contains other
+// characters than crosses and dots is skipped and reported instead of aborting
+fn read_burmeister_lenient<I: Iterator<Item = Result<String, Error>>>(
+    lines: &mut I,
+) -> (FormalContext<String>, Vec<FormatError>) {
+    let (objects, attributes) = match read_burmeister_names(lines, &|name| name.to_string()) {
+        Ok(names) => names,
+        Err(error) => return (FormalContext::new(), vec![error]),
+    };
+
+    let mut errors = Vec::new();
+    let mut incidence: HashSet<(usize, usize)> = HashSet::new();
+    for g in 0..objects.len() {
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(error)) => {
+                errors.push(FormatError::IoError(error));
+                continue;
+            }
+            None => {
+                errors.push(FormatError::InvalidFormat);
+                continue;
+            }
+        };
+        let row = line.trim_end();
+        if row.chars().count() != attributes.len()
+            || row.chars().any(|x| !matches!(x, 'X' | 'x' | '.'))
+        {
+            errors.push(FormatError::InvalidFormat);
+            continue;
+        }
+        for (m, x) in row.chars().enumerate() {
+            if x == 'X' || x == 'x' {
+                incidence.insert((g, m));
+            }
+        }
+    }
+
+    (
+        FormalContext::construct(objects, attributes, incidence),
+        errors,
+    )
+}
+
 #[derive(Clone)]
 /// The main data structure of formal concept analysis. The incidence is given as a set of tuples, referring to the indices of the object and attribute vectors.
+///
+/// The objects and attributes are read through `objects` and `attributes` and changed through methods like
+/// `add_object` or `change_object_name`, which keep the cached sets of `all_objects` and `all_attributes` in sync.
 pub struct FormalContext<T> {
-    pub objects: Vec<T>,
-    pub attributes: Vec<T>,
+    pub(crate) objects: Vec<T>,
+    pub(crate) attributes: Vec<T>,
     pub incidence: HashSet<(usize, usize)>,
     pub atomic_object_derivations: Vec<BitSet>,
     pub atomic_attribute_derivations: Vec<BitSet>,
+    all_objects: BitSet,
+    all_attributes: BitSet,
+}
+
+// Removes the carriage return of a line ending in CRLF, other trailing whitespace belongs to the line
+fn strip_carriage_return(line: &str) -> &str {
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+impl<T> Default for FormalContext<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> FormalContext<T> {
-    fn construct(objects: Vec<T>, attributes: Vec<T>, incidence: HashSet<(usize, usize)>) -> Self {
+    pub(crate) fn construct(
+        objects: Vec<T>,
+        attributes: Vec<T>,
+        incidence: HashSet<(usize, usize)>,
+    ) -> Self {
         let mut atomic_object_derivations =
             vec![BitSet::with_capacity(attributes.len()); objects.len()];
         let mut atomic_attribute_derivations =
@@ -47,6 +214,8 @@ impl<T> FormalContext<T> {
         }
 
         FormalContext {
+            all_objects: (0..objects.len()).collect(),
+            all_attributes: (0..attributes.len()).collect(),
             objects,
             attributes,
             incidence,
@@ -60,48 +229,83 @@ impl<T> FormalContext<T> {
         Self::construct(Vec::new(), Vec::new(), HashSet::new())
     }
 
-    /// Reads a formal context in Burmeister format.
-    pub fn from(contents: &[u8]) -> Result<FormalContext<String>, FormatError> {
-        let mut lines = contents.lines();
-
-        if lines.next().ok_or(FormatError::InvalidFormat)?? != "B" {
-            return Err(FormatError::InvalidFormat);
+    /// Creates an empty formal context with space reserved for the given number of objects and attributes.
+    pub fn with_capacity(objects: usize, attributes: usize) -> Self {
+        FormalContext {
+            objects: Vec::with_capacity(objects),
+            attributes: Vec::with_capacity(attributes),
+            incidence: HashSet::new(),
+            atomic_object_derivations: Vec::with_capacity(objects),
+            atomic_attribute_derivations: Vec::with_capacity(attributes),
+            all_objects: BitSet::with_capacity(objects),
+            all_attributes: BitSet::with_capacity(attributes),
         }
+    }
 
-        lines.next().ok_or(FormatError::InvalidFormat)??;
+    /// Returns the objects of the context.
+    pub fn objects(&self) -> &[T] {
+        &self.objects
+    }
 
-        let object_count: usize = lines.next().ok_or(FormatError::InvalidFormat)??.parse()?;
-        let attribute_count: usize = lines.next().ok_or(FormatError::InvalidFormat)??.parse()?;
+    /// Returns the attributes of the context.
+    pub fn attributes(&self) -> &[T] {
+        &self.attributes
+    }
 
-        lines.next().ok_or(FormatError::InvalidFormat)??;
+    /// Returns the set of all object indices, which is kept up to date instead of being rebuilt on every use.
+    pub fn all_objects(&self) -> &BitSet {
+        &self.all_objects
+    }
 
-        let mut objects: Vec<String> = Vec::with_capacity(object_count);
-        for _ in 0..object_count {
-            objects.push(lines.next().ok_or(FormatError::InvalidFormat)??);
-        }
+    /// Returns the set of all attribute indices, which is kept up to date instead of being rebuilt on every use.
+    pub fn all_attributes(&self) -> &BitSet {
+        &self.all_attributes
+    }
 
-        let mut attributes: Vec<String> = Vec::with_capacity(object_count);
-        for _ in 0..attribute_count {
-            attributes.push(lines.next().ok_or(FormatError::InvalidFormat)??);
-        }
+    /// Reads a formal context in Burmeister format.
+    pub fn from(contents: &[u8]) -> Result<FormalContext<String>, FormatError> {
+        read_burmeister(&mut contents.lines(), &|name| name.to_string())
+    }
 
-        let mut incidence: HashSet<(usize, usize)> = HashSet::new();
-        for g in 0..object_count {
-            let line = lines.next().ok_or(FormatError::InvalidFormat)??;
-            for (m, x) in line.chars().enumerate() {
-                if x == 'X' || x == 'x' {
-                    incidence.insert((g, m));
+    /// Reads a formal context in Burmeister format, skipping malformed rows of the table and returning their errors.
+    pub fn from_lenient(contents: &[u8]) -> (FormalContext<String>, Vec<FormatError>) {
+        read_burmeister_lenient(&mut contents.lines())
+    }
+
+    /// Reads a formal context in Burmeister format, turning the object and attribute names into T.
+    pub fn from_parsed<F: Fn(&str) -> T>(
+        contents: &[u8],
+        parse_name: F,
+    ) -> Result<FormalContext<T>, FormatError> {
+        read_burmeister(&mut contents.lines(), &parse_name)
+    }
+
+    /// Reads several formal contexts in Burmeister format, which may be separated by blank lines.
+    pub fn from_multi<R: BufRead>(reader: R) -> Result<Vec<FormalContext<String>>, FormatError> {
+        let mut lines = reader.lines().peekable();
+        let mut contexts = Vec::new();
+
+        loop {
+            while let Some(Ok(line)) = lines.peek() {
+                if line.trim().is_empty() {
+                    lines.next();
+                } else {
+                    break;
                 }
             }
+            if lines.peek().is_none() {
+                break;
+            }
+            contexts.push(read_burmeister(&mut lines, &|name| name.to_string())?);
         }
 
-        Ok(FormalContext::construct(objects, attributes, incidence))
+        Ok(contexts)
     }
 
     /// Computes the attribute derivation of a given set of indices.
     pub fn index_attribute_derivation(&self, attributes: &BitSet) -> BitSet {
         match attributes.len() {
-            0 => (0..self.objects.len()).collect(),
+            0 => self.all_objects.clone(),
             1 => self.atomic_attribute_derivations[attributes.iter().next().unwrap()].clone(),
             _ => {
                 let mut iter = attributes.iter();
@@ -117,7 +321,7 @@ impl<T> FormalContext<T> {
     /// Computes the object derivation of a given set of indices.
     pub fn index_object_derivation(&self, objects: &BitSet) -> BitSet {
         match objects.len() {
-            0 => (0..self.attributes.len()).collect(),
+            0 => self.all_attributes.clone(),
             1 => self.atomic_object_derivations[objects.iter().next().unwrap()].clone(),
             _ => {
                 let mut iter = objects.iter();
@@ -142,11 +346,311 @@ impl<T> FormalContext<T> {
         self.index_attribute_derivation(&attributes)
     }
 
+    /// Computes the closure of a given set of attribute indices, the same as `index_attribute_hull`.
+    ///
+    /// ```
+    /// use bit_set::BitSet;
+    /// use odis::FormalContext;
+    ///
+    /// let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\nXX\n").unwrap();
+    ///
+    /// // {y} -> {x, y}
+    /// let attributes: BitSet = [1].into_iter().collect();
+    /// assert_eq!(context.closure(&attributes), [0, 1].into_iter().collect());
+    /// ```
+    pub fn closure(&self, attributes: &BitSet) -> BitSet {
+        self.index_attribute_hull(attributes)
+    }
+
+    /// Computes the closure of a given set of object indices, the same as `index_object_hull`.
+    ///
+    /// ```
+    /// use bit_set::BitSet;
+    /// use odis::FormalContext;
+    ///
+    /// let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\nXX\n").unwrap();
+    ///
+    /// // {a} -> {a, b}
+    /// let objects: BitSet = [0].into_iter().collect();
+    /// assert_eq!(context.object_closure(&objects), [0, 1].into_iter().collect());
+    /// ```
+    pub fn object_closure(&self, objects: &BitSet) -> BitSet {
+        self.index_object_hull(objects)
+    }
+
+    /// Returns the object indices of an extent in ascending order.
+    ///
+    /// ```
+    /// use bit_set::BitSet;
+    /// use odis::FormalContext;
+    ///
+    /// let extent: BitSet = [3, 0, 2].into_iter().collect();
+    /// assert_eq!(FormalContext::<String>::extent_indices(&extent), vec![0, 2, 3]);
+    /// ```
+    pub fn extent_indices(extent: &BitSet) -> Vec<usize> {
+        extent.iter().collect()
+    }
+
+    /// Returns the attribute indices of an intent in ascending order.
+    ///
+    /// ```
+    /// use bit_set::BitSet;
+    /// use odis::FormalContext;
+    ///
+    /// let intent: BitSet = [4, 1].into_iter().collect();
+    /// assert_eq!(FormalContext::<String>::intent_indices(&intent), vec![1, 4]);
+    /// ```
+    pub fn intent_indices(intent: &BitSet) -> Vec<usize> {
+        intent.iter().collect()
+    }
+
+    /// Restricts the context to the given objects, which are named by their index in this context.
+    pub(crate) fn object_restriction(&self, objects: &BitSet) -> FormalContext<usize> {
+        let objects: Vec<usize> = objects.iter().collect();
+        let incidence = objects
+            .iter()
+            .enumerate()
+            .flat_map(|(index, &g)| {
+                self.atomic_object_derivations[g]
+                    .iter()
+                    .map(move |m| (index, m))
+            })
+            .collect();
+        FormalContext::construct(objects, (0..self.attributes.len()).collect(), incidence)
+    }
+
+    /// Computes the Jaccard index of the attributes of two objects, which is 1 if both have no attributes.
+    pub fn object_similarity(&self, g1: usize, g2: usize) -> f64 {
+        let a = &self.atomic_object_derivations[g1];
+        let b = &self.atomic_object_derivations[g2];
+        let union = a.union(b).count();
+        if union == 0 {
+            return 1.0;
+        }
+        a.intersection(b).count() as f64 / union as f64
+    }
+
+    /// Returns the k objects most similar to the given object, sorted by descending similarity.
+    pub fn nearest_objects(&self, g: usize, k: usize) -> Vec<(usize, f64)> {
+        let mut similarities: Vec<(usize, f64)> = (0..self.objects.len())
+            .filter(|&other| other != g)
+            .map(|other| (other, self.object_similarity(g, other)))
+            .collect();
+        similarities.sort_by(|x, y| y.1.total_cmp(&x.1));
+        similarities.truncate(k);
+        similarities
+    }
+
+    /// Computes the separation index, the area of the concept divided by the number of crosses in the rows of its extent
+    /// and the columns of its intent, which is 1 if the concept is separated from the rest of the context.
+    pub fn separation_index(&self, concept: &(BitSet, BitSet)) -> f64 {
+        let (extent, intent) = concept;
+        let area = extent.len() * intent.len();
+        let rows: usize = extent
+            .iter()
+            .map(|g| self.atomic_object_derivations[g].len())
+            .sum();
+        let columns: usize = intent
+            .iter()
+            .map(|m| self.atomic_attribute_derivations[m].len())
+            .sum();
+        if rows + columns == 0 {
+            return 0.0;
+        }
+        area as f64 / (rows + columns - area) as f64
+    }
+
+    /// Returns the object concept of the object at the specified index, the smallest concept containing it.
+    pub fn object_concept(&self, g: usize) -> (BitSet, BitSet) {
+        let intent = self.atomic_object_derivations[g].clone();
+        (self.index_attribute_derivation(&intent), intent)
+    }
+
+    /// Returns the attribute concept of the attribute at the specified index, the largest concept containing it.
+    pub fn attribute_concept(&self, m: usize) -> (BitSet, BitSet) {
+        let extent = self.atomic_attribute_derivations[m].clone();
+        let intent = self.index_object_derivation(&extent);
+        (extent, intent)
+    }
+
+    /// Returns the index of the object concept in the lattice of this context for every object.
+    pub fn object_concept_ids(&self, lattice: &ConceptLattice) -> Vec<usize> {
+        (0..self.objects.len())
+            .map(|g| lattice.concept_index(&self.object_concept(g)).unwrap())
+            .collect()
+    }
+
+    /// Returns the index of the attribute concept in the lattice of this context for every attribute.
+    pub fn attribute_concept_ids(&self, lattice: &ConceptLattice) -> Vec<usize> {
+        (0..self.attributes.len())
+            .map(|m| lattice.concept_index(&self.attribute_concept(m)).unwrap())
+            .collect()
+    }
+
+    /// Pairs every concept with the number of objects whose object concept it is.
+    pub fn concept_weights(&self) -> Vec<((BitSet, BitSet), usize)> {
+        // The object concept of an object is the concept with the intent of the object
+        let mut counts: HashMap<&BitSet, usize> = HashMap::new();
+        for intent in &self.atomic_object_derivations {
+            *counts.entry(intent).or_insert(0) += 1;
+        }
+        self.fcbo_index_concepts()
+            .map(|concept| {
+                let weight = counts.get(&concept.1).copied().unwrap_or(0);
+                (concept, weight)
+            })
+            .collect()
+    }
+
+    /// Returns the objects whose object concept is an atom of the lattice of this context.
+    pub fn extremal_objects(&self, lattice: &ConceptLattice) -> BitSet {
+        let atoms = lattice.atoms();
+        (0..self.objects.len())
+            .filter(|&g| {
+                lattice
+                    .concept_index(&self.object_concept(g))
+                    .is_some_and(|c| atoms.contains(c))
+            })
+            .collect()
+    }
+
+    /// Computes the intent shared by all objects outside of the given extent.
+    pub fn extent_complement_intent(&self, extent: &BitSet) -> BitSet {
+        let complement: BitSet = self.all_objects.difference(extent).collect();
+        self.index_object_derivation(&complement)
+    }
+
+    /// Returns the bottom concept, whose intent is the closure of all attributes.
+    pub fn full_attribute_set_concept(&self) -> (BitSet, BitSet) {
+        let extent = self.index_attribute_derivation(&self.all_attributes);
+        let intent = self.index_object_derivation(&extent);
+        (extent, intent)
+    }
+
+    /// Returns the top concept, whose intent is the closure of the empty attribute set.
+    pub fn empty_attribute_set_concept(&self) -> (BitSet, BitSet) {
+        let extent = self.index_attribute_derivation(&BitSet::new());
+        let intent = self.index_object_derivation(&extent);
+        (extent, intent)
+    }
+
+    /// Returns the attributes which no object has.
+    pub fn empty_attributes(&self) -> BitSet {
+        (0..self.attributes.len())
+            .filter(|&m| self.atomic_attribute_derivations[m].is_empty())
+            .collect()
+    }
+
+    /// Returns the attributes which every object has.
+    pub fn full_attributes(&self) -> BitSet {
+        (0..self.attributes.len())
+            .filter(|&m| self.atomic_attribute_derivations[m].len() == self.objects.len())
+            .collect()
+    }
+
+    /// Returns the attributes in every intent, which are the attributes shared by all objects.
+    pub fn universal_attributes(&self) -> BitSet {
+        self.index_object_derivation(&self.all_objects)
+    }
+
+    /// Returns the objects which have no attribute.
+    pub fn empty_objects(&self) -> BitSet {
+        (0..self.objects.len())
+            .filter(|&g| self.atomic_object_derivations[g].is_empty())
+            .collect()
+    }
+
+    /// Returns the objects which have every attribute.
+    pub fn full_objects(&self) -> BitSet {
+        (0..self.objects.len())
+            .filter(|&g| self.atomic_object_derivations[g].len() == self.attributes.len())
+            .collect()
+    }
+
+    /// Returns the attributes whose closure contains the given attribute, including the attribute itself.
+    pub fn determines(&self, m: usize) -> BitSet {
+        (0..self.attributes.len())
+            .filter(|&n| {
+                self.atomic_attribute_derivations[n]
+                    .is_subset(&self.atomic_attribute_derivations[m])
+            })
+            .collect()
+    }
+
+    /// Returns the edges (m, n) for which the single attribute m implies the different attribute n.
+    pub fn attribute_implication_graph(&self) -> Vec<(usize, usize)> {
+        let mut edges = Vec::new();
+        for (m, closure) in self.attribute_order_matrix().iter().enumerate() {
+            for n in closure {
+                if n != m {
+                    edges.push((m, n));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns the closure of every single attribute, the row of m contains all attributes implied by m.
+    pub fn attribute_order_matrix(&self) -> Vec<BitSet> {
+        (0..self.attributes.len())
+            .map(|m| self.index_attribute_hull(&BitSet::from_iter([m])))
+            .collect()
+    }
+
+    /// Returns the attributes implied by the known attributes and the attributes which some object with the known
+    /// attributes has without being implied, neither of them containing the known attributes.
+    pub fn attribute_recommendations(&self, known: &BitSet) -> (BitSet, BitSet) {
+        let hull = self.index_attribute_hull(known);
+        let certain: BitSet = hull.difference(known).collect();
+
+        let mut possible = BitSet::new();
+        for g in &self.index_attribute_derivation(known) {
+            possible.union_with(&self.atomic_object_derivations[g]);
+        }
+        possible.difference_with(&hull);
+        (certain, possible)
+    }
+
+    /// Classifies the context as empty, without incidence, fully incident or general.
+    pub fn kind(&self) -> ContextKind {
+        if self.objects.is_empty() || self.attributes.is_empty() {
+            ContextKind::Empty
+        } else if self.incidence.is_empty() {
+            ContextKind::NoIncidence
+        } else if self.incidence.len() == self.objects.len() * self.attributes.len() {
+            ContextKind::Full
+        } else {
+            ContextKind::General
+        }
+    }
+
+    /// Checks if the implication from premise to conclusion holds in the context.
+    pub fn implication_holds(&self, premise: &BitSet, conclusion: &BitSet) -> bool {
+        conclusion.is_subset(&self.index_attribute_hull(premise))
+    }
+
+    /// Checks if the mapping sigma from the objects of the other context to the objects of this context is a scale measure,
+    /// i.e. the preimage of every extent of this context is an extent of the other context.
+    pub fn is_scale_measure_of<U>(&self, other: &FormalContext<U>, sigma: &[usize]) -> bool {
+        if sigma.len() != other.objects.len() {
+            return false;
+        }
+        // Preimages preserve intersections, so it suffices to check the attribute extents
+        self.atomic_attribute_derivations.iter().all(|extent| {
+            let preimage: BitSet = (0..sigma.len())
+                .filter(|&g| extent.contains(sigma[g]))
+                .collect();
+            other.index_object_hull(&preimage) == preimage
+        })
+    }
+
     /// Adds a new object with its corresponding attributes to the existing FormalContext.
     pub fn add_object(&mut self, new_object: T, attributes: &BitSet) {
         self.objects.push(new_object);
         let object_index = self.objects.len() - 1;
-        self.atomic_object_derivations.push(BitSet::new());
+        self.all_objects.insert(object_index);
+        self.atomic_object_derivations
+            .push(BitSet::with_capacity(self.attributes.len()));
 
         for attribute in attributes.iter() {
             self.incidence.insert((object_index, attribute));
@@ -159,10 +663,17 @@ impl<T> FormalContext<T> {
     pub fn add_attribute(&mut self, new_attribute: T, objects: &BitSet) {
         self.attributes.push(new_attribute);
         let attribute_index = self.attributes.len() - 1;
-        self.atomic_attribute_derivations.push(BitSet::new());
+        self.all_attributes.insert(attribute_index);
+        self.atomic_attribute_derivations
+            .push(BitSet::with_capacity(self.objects.len()));
+
+        // The object rows keep the width of the attribute set, as they had when the context was constructed
+        for derivation in self.atomic_object_derivations.iter_mut() {
+            derivation.reserve_len(self.attributes.len());
+        }
 
         for object in objects.iter() {
-            self.incidence.insert((attribute_index, object));
+            self.incidence.insert((object, attribute_index));
             self.atomic_object_derivations[object].insert(attribute_index);
             self.atomic_attribute_derivations[attribute_index].insert(object);
         }
@@ -193,6 +704,7 @@ impl<T> FormalContext<T> {
 
         self.atomic_object_derivations.remove(index);
         self.objects.remove(index);
+        self.all_objects.remove(self.objects.len());
     }
 
     /// Removes the attribute at the specified index from the existing FormalContext.
@@ -220,6 +732,36 @@ impl<T> FormalContext<T> {
 
         self.atomic_attribute_derivations.remove(index);
         self.attributes.remove(index);
+        self.all_attributes.remove(self.attributes.len());
+    }
+
+    /// Removes every object with the same attributes as an earlier object and returns the number of removed objects.
+    pub fn dedup_objects(&mut self) -> usize {
+        let mut seen = HashSet::new();
+        let duplicates: Vec<usize> = (0..self.objects.len())
+            .filter(|&g| !seen.insert(self.atomic_object_derivations[g].clone()))
+            .collect();
+
+        for &g in duplicates.iter().rev() {
+            self.remove_object(g);
+        }
+        duplicates.len()
+    }
+
+    /// Adds a new attribute which the objects having both given attributes have.
+    pub fn conjoin_attributes(&mut self, a: usize, b: usize, name: T) {
+        let objects: BitSet = self.atomic_attribute_derivations[a]
+            .intersection(&self.atomic_attribute_derivations[b])
+            .collect();
+        self.add_attribute(name, &objects);
+    }
+
+    /// Adds a new attribute which the objects having at least one of the given attributes have.
+    pub fn disjoin_attributes(&mut self, a: usize, b: usize, name: T) {
+        let objects: BitSet = self.atomic_attribute_derivations[a]
+            .union(&self.atomic_attribute_derivations[b])
+            .collect();
+        self.add_attribute(name, &objects);
     }
 
     /// Changes the name of a object at the specified index to the given name.
@@ -232,38 +774,310 @@ impl<T> FormalContext<T> {
         self.attributes[index] = name;
     }
 
+    /// Returns all concepts in lectic order of their intents, which does not depend on the algorithm used.
+    pub fn concepts_canonical_order(&self) -> Vec<(BitSet, BitSet)> {
+        let mut concepts: Vec<(BitSet, BitSet)> = self.fcbo_index_concepts().collect();
+        sort_canonical(&mut concepts);
+        concepts
+    }
+
+    /// Returns the intents of all concepts packed into words of 64 bits, together with the number of words per intent.
+    pub fn concepts_packed(&self) -> (Vec<u64>, usize) {
+        let stride = self.attributes.len().div_ceil(64);
+        let mut packed = Vec::new();
+        for (_, intent) in self.fcbo_index_concepts() {
+            let start = packed.len();
+            packed.resize(start + stride, 0);
+            for m in &intent {
+                packed[start + m / 64] |= 1 << (m % 64);
+            }
+        }
+        (packed, stride)
+    }
+
     /// In place sorts the concepts in lectic order.
     pub fn sort_lectic_order(&self, concepts: &mut [(BitSet, BitSet)]) {
-        let lenght = self.attributes.len();
-        let weight: Vec<usize> = (1..(lenght + 1)).map(|x| 2_usize.pow(x as u32)).collect();
+        sort_canonical(concepts);
+    }
+
+    /// Checks if the object at the specified index is reducible, i.e. its intent is the intersection of the intents strictly containing it.
+    pub fn is_object_reducible(&self, index: usize) -> bool {
+        let intent = &self.atomic_object_derivations[index];
+        let mut intersection = self.all_attributes.clone();
+        for other in &self.atomic_object_derivations {
+            if intent.is_subset(other) && intent != other {
+                intersection.intersect_with(other);
+            }
+        }
+        *intent == intersection
+    }
+
+    /// Checks if the attribute at the specified index is reducible, i.e. its extent is the intersection of the extents strictly containing it.
+    pub fn is_attribute_reducible(&self, index: usize) -> bool {
+        let extent = &self.atomic_attribute_derivations[index];
+        let mut intersection = self.all_objects.clone();
+        for other in &self.atomic_attribute_derivations {
+            if extent.is_subset(other) && extent != other {
+                intersection.intersect_with(other);
+            }
+        }
+        *extent == intersection
+    }
+
+    /// Returns the number of reducible objects and reducible attributes.
+    pub fn reducibility_counts(&self) -> (usize, usize) {
+        let objects = (0..self.objects.len())
+            .filter(|&g| self.is_object_reducible(g))
+            .count();
+        let attributes = (0..self.attributes.len())
+            .filter(|&m| self.is_attribute_reducible(m))
+            .count();
+        (objects, attributes)
+    }
+
+    /// Checks if no two objects have the same intent and no two attributes have the same extent.
+    pub fn is_clarified(&self) -> bool {
+        let intents: HashSet<&BitSet> = self.atomic_object_derivations.iter().collect();
+        let extents: HashSet<&BitSet> = self.atomic_attribute_derivations.iter().collect();
+        intents.len() == self.objects.len() && extents.len() == self.attributes.len()
+    }
+
+    /// Checks if the context is clarified and has no reducible objects or attributes.
+    pub fn is_reduced(&self) -> bool {
+        self.is_clarified() && self.reducibility_counts() == (0, 0)
+    }
+
+    /// Partitions the objects into classes of objects which have the same of the given attributes, ordered by their first object.
+    pub fn indiscernibility_classes(&self, attributes: &BitSet) -> Vec<BitSet> {
+        let mut class_indices: HashMap<BitSet, usize> = HashMap::new();
+        let mut classes: Vec<BitSet> = Vec::new();
+        for (g, intent) in self.atomic_object_derivations.iter().enumerate() {
+            let row: BitSet = intent.intersection(attributes).collect();
+            let index = *class_indices.entry(row).or_insert_with(|| {
+                classes.push(BitSet::new());
+                classes.len() - 1
+            });
+            classes[index].insert(g);
+        }
+        classes
+    }
+
+    /// Returns the objects whose indiscernibility class under the given attributes is contained in the target objects.
+    pub fn lower_approximation(&self, target: &BitSet, attributes: &BitSet) -> BitSet {
+        self.indiscernibility_classes(attributes)
+            .into_iter()
+            .filter(|class| class.is_subset(target))
+            .fold(BitSet::new(), |approximation, class| {
+                approximation.union(&class).collect()
+            })
+    }
+
+    /// Returns the objects whose indiscernibility class under the given attributes meets the target objects.
+    pub fn upper_approximation(&self, target: &BitSet, attributes: &BitSet) -> BitSet {
+        self.indiscernibility_classes(attributes)
+            .into_iter()
+            .filter(|class| !class.is_disjoint(target))
+            .fold(BitSet::new(), |approximation, class| {
+                approximation.union(&class).collect()
+            })
+    }
+}
+
+impl<T: Display> FormalContext<T> {
+    /// Writes a summary of the context with its size, density, concepts, lattice height and canonical basis.
+    pub fn analysis_report(&self) -> String {
+        let names = |names: &[T], set: &BitSet| {
+            let names: Vec<String> = set.iter().map(|i| names[i].to_string()).collect();
+            format!("{{{}}}", names.join(", "))
+        };
+        let lattice = ConceptLattice::new(self);
+        let basis = self.canonical_basis();
+        let cells = self.objects.len() * self.attributes.len();
+        let density = if cells == 0 {
+            0.0
+        } else {
+            self.incidence.len() as f64 / cells as f64
+        };
+
+        let mut report = String::new();
+        report.push_str(&format!("Objects: {}\n", self.objects.len()));
+        report.push_str(&format!("Attributes: {}\n", self.attributes.len()));
+        report.push_str(&format!("Density: {:.3}\n", density));
+        report.push_str(&format!("Concepts: {}\n", lattice.concepts.len()));
+        report.push_str(&format!("Height: {}\n", lattice.height()));
+        for (label, index) in [("Top", lattice.top()), ("Bottom", lattice.bottom())] {
+            let (extent, intent) = &lattice.concepts[index];
+            report.push_str(&format!(
+                "{} concept: {} x {}\n",
+                label,
+                names(&self.objects, extent),
+                names(&self.attributes, intent)
+            ));
+        }
+        report.push_str(&format!("Canonical basis: {} implications\n", basis.len()));
+        for (premise, conclusion) in &basis {
+            report.push_str(&format!(
+                "{} -> {}\n",
+                names(&self.attributes, premise),
+                names(&self.attributes, conclusion)
+            ));
+        }
+        report
+    }
+
+    /// Writes the context in Burmeister format.
+    pub fn write_burmeister<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writeln!(
+            writer,
+            "B\n\n{}\n{}\n",
+            self.objects.len(),
+            self.attributes.len()
+        )?;
+        for name in self.objects.iter().chain(self.attributes.iter()) {
+            writeln!(writer, "{}", name)?;
+        }
+        for intent in &self.atomic_object_derivations {
+            let row: String = (0..self.attributes.len())
+                .map(|m| if intent.contains(m) { 'X' } else { '.' })
+                .collect();
+            writeln!(writer, "{}", row)?;
+        }
+        Ok(())
+    }
+}
 
-        let mut order: Vec<(usize, usize)> = Vec::new();
+impl<T: Clone> FormalContext<T> {
+    /// Creates the subcontext on the given objects and attributes, keeping their order.
+    pub fn subcontext(&self, objects: &BitSet, attributes: &BitSet) -> FormalContext<T> {
+        let object_indices: Vec<usize> = objects.iter().collect();
+        let attribute_indices: Vec<usize> = attributes.iter().collect();
 
-        for (index, (_, set)) in concepts.iter().enumerate() {
-            let mut sum = 0;
-            for n in set {
-                sum += weight[lenght - 1 - n]
+        let mut incidence = HashSet::new();
+        for (g, &old_g) in object_indices.iter().enumerate() {
+            for (m, &old_m) in attribute_indices.iter().enumerate() {
+                if self.atomic_object_derivations[old_g].contains(old_m) {
+                    incidence.insert((g, m));
+                }
             }
-            order.push((index, sum));
         }
 
-        order.sort_by(|x, y| x.1.cmp(&y.1));
+        FormalContext::construct(
+            object_indices
+                .iter()
+                .map(|&g| self.objects[g].clone())
+                .collect(),
+            attribute_indices
+                .iter()
+                .map(|&m| self.attributes[m].clone())
+                .collect(),
+            incidence,
+        )
+    }
+
+    /// Creates the subcontext on the given attributes and all objects, its extents are exactly the extents
+    /// of the original context which are intersections of the kept attribute extents.
+    pub fn attribute_zoom(&self, keep: &BitSet) -> FormalContext<T> {
+        self.subcontext(&self.all_objects, keep)
+    }
+
+    /// Computes the concepts of the attribute zoom on every prefix of the order, from the first attribute alone to the
+    /// whole order. The intents are given in the attribute indices of this context. Repeated indices and indices
+    /// without an attribute are ignored, so such a prefix has the same concepts as the one before it.
+    pub fn attribute_stream_lattice(&self, order: &[usize]) -> Vec<Vec<(BitSet, BitSet)>> {
+        (1..=order.len())
+            .map(|length| {
+                let kept: BitSet = order[..length]
+                    .iter()
+                    .copied()
+                    .filter(|&m| m < self.attributes.len())
+                    .collect();
+                let prefix: Vec<usize> = kept.iter().collect();
+                let zoomed = self.attribute_zoom(&kept);
+                zoomed
+                    .fcbo_index_concepts()
+                    .map(|(extent, intent)| (extent, intent.iter().map(|m| prefix[m]).collect()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Creates the direct product, whose objects and attributes are pairs and a pair of objects has a pair of attributes
+    /// if one of the objects has the corresponding attribute. Its concept lattice is the tensor product of the two
+    /// concept lattices, which is larger than their direct product in general.
+    pub fn direct_product<U: Clone>(&self, other: &FormalContext<U>) -> FormalContext<(T, U)> {
+        let pair_index = |a: usize, b: usize, count: usize| a * count + b;
 
-        for index in 0..(order.len() - 1) {
-            let swap = order[index].0;
-            if index != swap {
-                concepts.swap(index, swap);
-                let correction = order.iter().position(|x| x.0 == index).unwrap();
-                order[index] = (0, 0);
-                order[correction] = (swap, 0);
+        let mut incidence = HashSet::new();
+        for g in 0..self.objects.len() {
+            for h in 0..other.objects.len() {
+                for m in 0..self.attributes.len() {
+                    for n in 0..other.attributes.len() {
+                        if self.atomic_object_derivations[g].contains(m)
+                            || other.atomic_object_derivations[h].contains(n)
+                        {
+                            incidence.insert((
+                                pair_index(g, h, other.objects.len()),
+                                pair_index(m, n, other.attributes.len()),
+                            ));
+                        }
+                    }
+                }
             }
         }
+
+        let pairs = |first: &[T], second: &[U]| -> Vec<(T, U)> {
+            first
+                .iter()
+                .flat_map(|a| second.iter().map(move |b| (a.clone(), b.clone())))
+                .collect()
+        };
+        FormalContext::construct(
+            pairs(&self.objects, &other.objects),
+            pairs(&self.attributes, &other.attributes),
+            incidence,
+        )
+    }
+
+    /// Creates the standard context by clarifying and removing all reducible objects and attributes,
+    /// the concept lattice stays the same up to isomorphism.
+    pub fn standardize(&self) -> FormalContext<T> {
+        let mut seen = HashSet::new();
+        let objects: BitSet = (0..self.objects.len())
+            .filter(|&g| seen.insert(&self.atomic_object_derivations[g]))
+            .collect();
+        let mut seen = HashSet::new();
+        let attributes: BitSet = (0..self.attributes.len())
+            .filter(|&m| seen.insert(&self.atomic_attribute_derivations[m]))
+            .collect();
+        let clarified = self.subcontext(&objects, &attributes);
+
+        // In a clarified context all reducible objects and attributes can be removed at once
+        let objects: BitSet = (0..clarified.objects.len())
+            .filter(|&g| !clarified.is_object_reducible(g))
+            .collect();
+        let attributes: BitSet = (0..clarified.attributes.len())
+            .filter(|&m| !clarified.is_attribute_reducible(m))
+            .collect();
+        clarified.subcontext(&objects, &attributes)
+    }
+
+    /// Returns one irreducible object for every join-irreducible object concept, the subcontext on these objects and
+    /// all attributes has the same concept lattice.
+    pub fn object_generating_set(&self) -> BitSet {
+        let mut seen = HashSet::new();
+        (0..self.objects.len())
+            .filter(|&g| {
+                seen.insert(&self.atomic_object_derivations[g]) && !self.is_object_reducible(g)
+            })
+            .collect()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::FormalContext;
+    use super::{sort_canonical, ContextKind, FormalContext};
+    use crate::util::{bitset_from_indices, unpack_intent};
+    use crate::ConceptLattice;
     use bit_set::BitSet;
     use itertools::Itertools;
     use std::fs;
@@ -288,6 +1102,43 @@ mod tests {
         assert!(context.incidence.contains(&(1, 6)));
     }
 
+    #[test]
+    fn test_read_context_keeps_trailing_spaces() {
+        let context =
+            FormalContext::<String>::from(b"B\r\n\r\n1\r\n2\r\n\r\ng \r\na\r\nb \nX.\r\n").unwrap();
+        assert_eq!(context.objects, vec!["g ".to_string()]);
+        assert_eq!(context.attributes, vec!["a".to_string(), "b ".to_string()]);
+    }
+
+    #[test]
+    fn test_read_context_crlf() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles_crlf.cxt").unwrap())
+                .unwrap();
+        let expected =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        assert_eq!(context.objects, expected.objects);
+        assert_eq!(context.attributes, expected.attributes);
+        assert_eq!(context.incidence, expected.incidence);
+        for name in context.objects.iter().chain(context.attributes.iter()) {
+            assert!(!name.ends_with('\r'));
+        }
+    }
+
+    #[test]
+    fn test_read_multi() {
+        let contexts =
+            FormalContext::<String>::from_multi(&fs::read("test_data/multi.cxt").unwrap()[..])
+                .unwrap();
+        assert_eq!(contexts.len(), 2);
+        assert_eq!(contexts[0].objects.len(), 7);
+        assert_eq!(contexts[0].attributes.len(), 5);
+        assert_eq!(contexts[0].incidence.len(), 12);
+        assert_eq!(contexts[1].objects.len(), 4);
+        assert_eq!(contexts[1].attributes.len(), 6);
+        assert_eq!(contexts[1].incidence.len(), 13);
+    }
+
     #[test]
     fn text_index_derivations() {
         let context =
@@ -301,51 +1152,51 @@ mod tests {
         .unwrap();
         assert_eq!(
             // [0]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b10000000])),
+            context.index_attribute_derivation(&bitset_from_indices(&[0])),
             // [0, 1, 2, 3, 4, 5, 6, 7]
-            BitSet::from_bytes(&[0b11111111])
+            bitset_from_indices(&[0, 1, 2, 3, 4, 5, 6, 7])
         );
         assert_eq!(
             // [1]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b01000000])),
+            context.index_attribute_derivation(&bitset_from_indices(&[1])),
             // [0, 1, 2, 4, 5]
-            BitSet::from_bytes(&[0b11101100])
+            bitset_from_indices(&[0, 1, 2, 4, 5])
         );
         assert_eq!(
             // [0, 1]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b11000000])),
+            context.index_attribute_derivation(&bitset_from_indices(&[0, 1])),
             // [0, 1, 2, 4, 5]
-            BitSet::from_bytes(&[0b11101100])
+            bitset_from_indices(&[0, 1, 2, 4, 5])
         );
         assert_eq!(
             // [0, 1, 2, 3, 4, 5]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b11111100])),
+            context.index_attribute_derivation(&bitset_from_indices(&[0, 1, 2, 3, 4, 5])),
             // []
-            BitSet::from_bytes(&[0b00000000])
+            bitset_from_indices(&[])
         );
         assert_eq!(
             // [3, 4]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b00011000])),
+            context.index_attribute_derivation(&bitset_from_indices(&[3, 4])),
             // [6]
-            BitSet::from_bytes(&[0b00000010])
+            bitset_from_indices(&[6])
         );
         assert_eq!(
             // [2, 6, 7]
-            context.index_attribute_derivation(&BitSet::from_bytes(&[0b00100011])),
+            context.index_attribute_derivation(&bitset_from_indices(&[2, 6, 7])),
             // [2, 3]
-            BitSet::from_bytes(&[0b00110000])
+            bitset_from_indices(&[2, 3])
         );
         assert_eq!(
             // [0]
-            context.index_object_derivation(&BitSet::from_bytes(&[0b10000000])),
+            context.index_object_derivation(&bitset_from_indices(&[0])),
             // [0, 1, 6]
-            BitSet::from_bytes(&[0b11000010])
+            bitset_from_indices(&[0, 1, 6])
         );
         assert_eq!(
             // [0, 1, 2, 3]
-            context.index_object_derivation(&BitSet::from_bytes(&[0b11110000])),
+            context.index_object_derivation(&bitset_from_indices(&[0, 1, 2, 3])),
             // [0, 6]
-            BitSet::from_bytes(&[0b10000010])
+            bitset_from_indices(&[0, 6])
         );
     }
 
@@ -356,12 +1207,12 @@ mod tests {
         )
         .unwrap();
         assert_eq!(
-            context.index_attribute_hull(&BitSet::from_bytes(&[0b00000000])),
-            BitSet::from_bytes(&[0b10000000])
+            context.index_attribute_hull(&bitset_from_indices(&[])),
+            bitset_from_indices(&[0])
         );
         assert_eq!(
-            context.index_object_hull(&BitSet::from_bytes(&[0b00000000])),
-            BitSet::from_bytes(&[0b00000000])
+            context.index_object_hull(&bitset_from_indices(&[])),
+            bitset_from_indices(&[])
         );
         assert_eq!(
             context.index_attribute_hull(&(0..context.attributes.len()).collect()),
@@ -397,4 +1248,1102 @@ mod tests {
 
         assert!(concepts_sorted == concepts_unsorted);
     }
+
+    #[test]
+    fn lectic_sort_wide_and_empty() {
+        let context = FormalContext::<String>::new();
+
+        let mut concepts: Vec<(BitSet, BitSet)> = Vec::new();
+        context.sort_lectic_order(&mut concepts);
+        assert!(concepts.is_empty());
+
+        // Attribute 0 is the most significant, so {69} comes before {0}
+        let mut concepts = vec![
+            (BitSet::new(), bitset_from_indices(&[0])),
+            (BitSet::new(), bitset_from_indices(&[69])),
+        ];
+        context.sort_lectic_order(&mut concepts);
+        assert_eq!(
+            concepts,
+            vec![
+                (BitSet::new(), bitset_from_indices(&[69])),
+                (BitSet::new(), bitset_from_indices(&[0])),
+            ]
+        );
+    }
+
+    #[test]
+    fn reducibility_counts() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert_eq!(context.reducibility_counts(), (0, 0));
+
+        // {1} is the intersection of {1,3} and {1,4}
+        context.add_object("7".to_string(), &bitset_from_indices(&[1]));
+
+        assert!(context.is_object_reducible(7));
+        assert_eq!(context.reducibility_counts(), (1, 0));
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut context = FormalContext::<usize>::with_capacity(1000, 3);
+        assert!(context.objects.capacity() >= 1000);
+        assert!(context.attributes.capacity() >= 3);
+
+        for m in 0..3 {
+            context.add_attribute(m, &BitSet::new());
+        }
+        for g in 0..1000 {
+            let attributes: BitSet = (0..3).filter(|m| g % (m + 2) == 0).collect();
+            context.add_object(g, &attributes);
+        }
+
+        assert_eq!(context.objects.len(), 1000);
+        for m in 0..3 {
+            let mut attribute = BitSet::new();
+            attribute.insert(m);
+            assert_eq!(
+                context.index_attribute_derivation(&attribute),
+                (0..1000).filter(|g| g % (m + 2) == 0).collect()
+            );
+        }
+    }
+
+    #[test]
+    fn object_similarity() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        // Albanien and Andorra
+        assert_eq!(context.object_similarity(0, 1), 0.5);
+        // Albanien and Belarus
+        assert_eq!(context.object_similarity(0, 2), 1.0 / 3.0);
+        // Belgien and Deutschland
+        assert_eq!(context.object_similarity(3, 7), 1.0);
+        assert_eq!(context.object_similarity(0, 0), 1.0);
+
+        context.add_object("a".to_string(), &BitSet::new());
+        context.add_object("b".to_string(), &BitSet::new());
+        assert_eq!(context.object_similarity(48, 49), 1.0);
+        assert_eq!(context.object_similarity(0, 48), 0.0);
+    }
+
+    #[test]
+    fn nearest_objects() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        for k in [0, 1, 5, 47, 100] {
+            let nearest = context.nearest_objects(0, k);
+            assert_eq!(nearest.len(), k.min(context.objects.len() - 1));
+            assert!(nearest.iter().all(|&(g, _)| g != 0));
+            for pair in nearest.windows(2) {
+                assert!(pair[0].1 >= pair[1].1);
+            }
+        }
+
+        // Belgien and Deutschland share all attributes
+        assert_eq!(context.nearest_objects(3, 1)[0].1, 1.0);
+    }
+
+    #[test]
+    fn extent_complement_intent() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        // The members of the EU share no attribute with the rest
+        let extent = context.atomic_attribute_derivations[0].clone();
+        assert_eq!(context.extent_complement_intent(&extent), BitSet::new());
+
+        // Belarus is only a member of the OSZE
+        let mut extent: BitSet = (0..context.objects.len()).collect();
+        extent.remove(2);
+        assert_eq!(
+            context.extent_complement_intent(&extent),
+            bitset_from_indices(&[5])
+        );
+
+        let extent: BitSet = (0..context.objects.len()).collect();
+        assert_eq!(
+            context.extent_complement_intent(&extent),
+            (0..context.attributes.len()).collect()
+        );
+    }
+
+    #[test]
+    fn top_and_bottom_concepts() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert_eq!(
+            context.full_attribute_set_concept(),
+            (BitSet::new(), bitset_from_indices(&[0, 1, 2, 3, 4]))
+        );
+        assert_eq!(
+            context.empty_attribute_set_concept(),
+            (bitset_from_indices(&[0, 1, 2, 3, 4, 5, 6]), BitSet::new())
+        );
+
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+
+        // Every living being needs water
+        assert_eq!(
+            context.empty_attribute_set_concept(),
+            ((0..8).collect(), bitset_from_indices(&[0]))
+        );
+    }
+
+    #[test]
+    fn empty_and_full_rows_and_columns() {
+        let context = FormalContext::<String>::from(
+            b"B\n\n4\n3\n\na\nb\nc\nd\nx\ny\nz\nX.X\nX..\n...\nX.X\n",
+        )
+        .unwrap();
+
+        assert_eq!(context.empty_attributes(), bitset_from_indices(&[1]));
+        assert_eq!(context.full_attributes(), BitSet::new());
+        assert_eq!(context.empty_objects(), bitset_from_indices(&[2]));
+        assert_eq!(context.full_objects(), BitSet::new());
+
+        let context =
+            FormalContext::<String>::from(b"B\n\n3\n3\n\na\nb\nc\nx\ny\nz\nX.X\nX..\nXXX\n")
+                .unwrap();
+
+        assert_eq!(context.empty_attributes(), BitSet::new());
+        assert_eq!(context.full_attributes(), bitset_from_indices(&[0]));
+        assert_eq!(context.empty_objects(), BitSet::new());
+        assert_eq!(context.full_objects(), bitset_from_indices(&[2]));
+    }
+
+    #[test]
+    fn add_many_attributes() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let original = context.clone();
+
+        for k in 0..100 {
+            let objects: BitSet = (0..context.objects.len())
+                .filter(|g| (g + k).is_multiple_of(3))
+                .collect();
+            context.add_attribute(format!("new {}", k), &objects);
+        }
+
+        assert_eq!(context.attributes.len(), original.attributes.len() + 100);
+        for m in 0..context.attributes.len() {
+            let expected: BitSet = if m < original.attributes.len() {
+                original.atomic_attribute_derivations[m].clone()
+            } else {
+                let k = m - original.attributes.len();
+                (0..context.objects.len())
+                    .filter(|g| (g + k).is_multiple_of(3))
+                    .collect()
+            };
+            assert_eq!(
+                context.index_attribute_derivation(&bitset_from_indices(&[m])),
+                expected
+            );
+            for g in 0..context.objects.len() {
+                assert_eq!(context.incidence.contains(&(g, m)), expected.contains(g));
+                assert_eq!(
+                    context.atomic_object_derivations[g].contains(m),
+                    expected.contains(g)
+                );
+            }
+        }
+        for g in 0..context.objects.len() {
+            assert_eq!(
+                context.index_object_derivation(&bitset_from_indices(&[g])),
+                context.atomic_object_derivations[g]
+            );
+        }
+    }
+
+    #[test]
+    fn clarified_and_reduced() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert!(context.is_clarified());
+        assert!(context.is_reduced());
+        assert_eq!(context.standardize().incidence, context.incidence);
+
+        context.add_object("7".to_string(), &bitset_from_indices(&[1, 3]));
+        assert!(!context.is_clarified());
+        assert!(!context.is_reduced());
+    }
+
+    #[test]
+    fn standardize() {
+        // Every living being needs water, so that attribute is the empty intersection of the others
+        let fixtures = [
+            ("test_data/triangles.cxt", true, true, 7, 5),
+            ("test_data/living_beings_and_water.cxt", true, false, 8, 8),
+            ("test_data/eu.cxt", false, false, 10, 7),
+            ("test_data/data_from_paper.cxt", false, false, 4, 4),
+        ];
+        for (path, clarified, reduced, object_count, attribute_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let standardized = context.standardize();
+
+            assert_eq!(context.is_clarified(), clarified, "{path}");
+            assert_eq!(context.is_reduced(), reduced, "{path}");
+            assert_eq!(standardized.objects.len(), object_count, "{path}");
+            assert_eq!(standardized.attributes.len(), attribute_count, "{path}");
+            assert!(standardized.is_clarified(), "{path}");
+            assert!(standardized.is_reduced(), "{path}");
+            assert!(context.lattice_isomorphic(&standardized), "{path}");
+        }
+    }
+
+    #[test]
+    fn test_read_parsed() {
+        let contents = fs::read("test_data/triangles.cxt").unwrap();
+        let parsed =
+            FormalContext::<u32>::from_parsed(&contents, |name| name.parse().unwrap()).unwrap();
+        let context = FormalContext::<String>::from(&contents).unwrap();
+
+        assert_eq!(parsed.objects, (0..7).collect::<Vec<u32>>());
+        assert_eq!(parsed.attributes, (0..5).collect::<Vec<u32>>());
+        assert_eq!(parsed.incidence, context.incidence);
+        assert_eq!(
+            parsed.atomic_object_derivations,
+            context.atomic_object_derivations
+        );
+    }
+
+    #[test]
+    fn attribute_zoom() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let keep = bitset_from_indices(&[0, 2, 3, 5]);
+        let kept: Vec<usize> = keep.iter().collect();
+
+        let zoomed = context.attribute_zoom(&keep);
+
+        assert_eq!(zoomed.objects, context.objects);
+        assert_eq!(
+            zoomed.attributes,
+            kept.iter()
+                .map(|&m| context.attributes[m].clone())
+                .collect::<Vec<_>>()
+        );
+
+        let extents: Vec<BitSet> = context.fcbo_index_concepts().map(|x| x.0).collect();
+        for (extent, intent) in zoomed.fcbo_index_concepts() {
+            assert!(extents.contains(&extent));
+
+            let original_intent: BitSet = intent.iter().map(|m| kept[m]).collect();
+            assert_eq!(
+                original_intent,
+                context
+                    .index_object_derivation(&extent)
+                    .intersection(&keep)
+                    .collect()
+            );
+        }
+    }
+
+    #[test]
+    fn concepts_canonical_order() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+            ("test_data/data_from_paper.cxt", 12),
+        ];
+        for (path, concept_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let canonical = context.concepts_canonical_order();
+
+            assert_eq!(canonical.len(), concept_count, "{path}");
+            assert_eq!(
+                canonical,
+                context.index_concepts().collect::<Vec<_>>(),
+                "{path}"
+            );
+            for mut concepts in [
+                context.index_concepts().collect::<Vec<_>>(),
+                context.fcbo_index_concepts().collect::<Vec<_>>(),
+                context.index_object_concepts().collect::<Vec<_>>(),
+            ] {
+                sort_canonical(&mut concepts);
+                assert_eq!(concepts, canonical, "{path}");
+            }
+        }
+    }
+
+    #[test]
+    fn sort_canonical_many_attributes() {
+        // {69} is lectically smaller than {0}, which needs more than 64 bits of weight
+        let mut concepts = vec![
+            (BitSet::new(), bitset_from_indices(&[0])),
+            (BitSet::new(), bitset_from_indices(&[69])),
+            (BitSet::new(), bitset_from_indices(&[0, 69])),
+            (BitSet::new(), BitSet::new()),
+        ];
+
+        sort_canonical(&mut concepts);
+
+        assert_eq!(
+            concepts,
+            vec![
+                (BitSet::new(), BitSet::new()),
+                (BitSet::new(), bitset_from_indices(&[69])),
+                (BitSet::new(), bitset_from_indices(&[0])),
+                (BitSet::new(), bitset_from_indices(&[0, 69])),
+            ]
+        );
+    }
+
+    #[test]
+    fn wide_context() {
+        let attribute_count = 20_000;
+        let incidence = (0..8)
+            .flat_map(|g| {
+                (0..attribute_count)
+                    .filter(move |m| m % (g + 2) == 0)
+                    .map(move |m| (g, m))
+            })
+            .collect();
+        let mut context =
+            FormalContext::construct((0..8).collect(), (0..attribute_count).collect(), incidence);
+
+        let all_attributes: BitSet = (0..attribute_count).collect();
+        let all_objects: BitSet = (0..8).collect();
+        assert_eq!(*context.all_attributes(), all_attributes);
+        assert_eq!(*context.all_objects(), all_objects);
+
+        for _ in 0..100 {
+            assert_eq!(
+                context.index_object_derivation(&BitSet::new()),
+                all_attributes
+            );
+            assert_eq!(
+                context.index_attribute_derivation(&BitSet::new()),
+                all_objects
+            );
+        }
+        assert_eq!(
+            context.full_attribute_set_concept(),
+            (BitSet::new(), all_attributes.clone())
+        );
+        assert_eq!(
+            context.extent_complement_intent(&bitset_from_indices(&[1, 2, 3, 4, 5, 6, 7])),
+            context.atomic_object_derivations[0]
+        );
+
+        context.remove_attribute(0);
+        context.remove_object(3);
+        context.add_attribute(attribute_count, &bitset_from_indices(&[0]));
+        context.add_object(8, &bitset_from_indices(&[1]));
+        assert_eq!(*context.all_attributes(), all_attributes);
+        assert_eq!(*context.all_objects(), all_objects);
+    }
+
+    #[test]
+    fn test_write_burmeister() {
+        let fixtures = [
+            "test_data/triangles.cxt",
+            "test_data/living_beings_and_water.cxt",
+            "test_data/eu.cxt",
+            "test_data/data_from_paper.cxt",
+        ];
+        for path in fixtures {
+            let contents = fs::read(path).unwrap();
+            let context = FormalContext::<String>::from(&contents).unwrap();
+
+            let mut output = Vec::new();
+            context.write_burmeister(&mut output).unwrap();
+            let read = FormalContext::<String>::from(&output).unwrap();
+
+            assert_eq!(read.objects, context.objects, "{path}");
+            assert_eq!(read.attributes, context.attributes, "{path}");
+            assert_eq!(read.incidence, context.incidence, "{path}");
+
+            // The files are written the same way, so they come back byte for byte up to the final line break
+            // missing in eu.cxt
+            let mut expected = contents.clone();
+            if !expected.ends_with(b"\n") {
+                expected.push(b'\n');
+            }
+            assert_eq!(output, expected, "{path}");
+        }
+    }
+
+    #[test]
+    fn test_write_burmeister_small() {
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\nXX\n").unwrap();
+
+        let mut output = Vec::new();
+        context.write_burmeister(&mut output).unwrap();
+
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "B\n\n2\n2\n\na\nb\nx\ny\nX.\nXX\n"
+        );
+    }
+
+    #[test]
+    fn dedup_objects() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let original = context.clone();
+
+        assert_eq!(context.dedup_objects(), 0);
+
+        context.add_object("7".to_string(), &bitset_from_indices(&[1, 3]));
+        context.add_object("8".to_string(), &bitset_from_indices(&[2]));
+        context.add_object("9".to_string(), &bitset_from_indices(&[0, 4]));
+        context.add_object("10".to_string(), &bitset_from_indices(&[2]));
+
+        assert_eq!(context.dedup_objects(), 3);
+        assert_eq!(context.objects.len(), original.objects.len() + 1);
+        assert_eq!(context.objects.last().unwrap(), "9");
+        assert_eq!(
+            context.atomic_object_derivations[..original.objects.len()],
+            original.atomic_object_derivations[..]
+        );
+        assert_eq!(context.dedup_objects(), 0);
+    }
+
+    #[test]
+    fn concepts_packed() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        let intents: Vec<BitSet> = context.fcbo_index_concepts().map(|x| x.1).collect();
+
+        let (packed, stride) = context.concepts_packed();
+
+        assert_eq!(stride, context.attributes.len().div_ceil(64));
+        assert_eq!(packed.len(), intents.len() * stride);
+        for (i, intent) in intents.iter().enumerate() {
+            assert_eq!(unpack_intent(&packed, i, stride), *intent);
+        }
+    }
+
+    #[test]
+    fn universal_attributes() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        assert_eq!(
+            context.universal_attributes(),
+            context.empty_attribute_set_concept().1
+        );
+        for (_, intent) in context.fcbo_index_concepts() {
+            assert!(context.universal_attributes().is_subset(&intent));
+        }
+
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(context.universal_attributes(), bitset_from_indices(&[0]));
+    }
+
+    #[test]
+    fn determines() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        // {0} -> {0,1,2} is the only implication with a single premise
+        assert_eq!(context.determines(0), bitset_from_indices(&[0]));
+        assert_eq!(context.determines(1), bitset_from_indices(&[0, 1]));
+        assert_eq!(context.determines(2), bitset_from_indices(&[0, 2]));
+        assert_eq!(context.determines(3), bitset_from_indices(&[3]));
+        assert_eq!(context.determines(4), bitset_from_indices(&[4]));
+
+        for m in 0..context.attributes.len() {
+            for n in &context.determines(m) {
+                assert!(context
+                    .implication_holds(&bitset_from_indices(&[n]), &bitset_from_indices(&[m])));
+            }
+        }
+    }
+
+    #[test]
+    fn direct_product() {
+        let diagonal = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX.\n.X\n").unwrap();
+        let product = diagonal.direct_product(&diagonal);
+
+        assert_eq!(product.objects.len(), 4);
+        assert_eq!(product.objects[1], ("a".to_string(), "b".to_string()));
+        // (a, b) has (x, x) through a and (y, y) through b, but not (y, x)
+        assert!(product.atomic_object_derivations[1].contains(0));
+        assert!(product.atomic_object_derivations[1].contains(3));
+        assert!(!product.atomic_object_derivations[1].contains(2));
+        // The square is a Boolean lattice, for those the tensor product is the product with 4 * 4 concepts
+        assert_eq!(product.fcbo_index_concepts().count(), 16);
+
+        // The tensor product of the three element chain with itself has 6 concepts, not the 9 of the product
+        let three_chain =
+            FormalContext::<String>::from(b"B\n\n3\n2\n\ng\nh\ni\na\nb\nXX\nX.\n..\n").unwrap();
+        assert_eq!(three_chain.fcbo_index_concepts().count(), 3);
+        assert_eq!(
+            three_chain
+                .direct_product(&three_chain)
+                .fcbo_index_concepts()
+                .count(),
+            6
+        );
+
+        // The tensor product of the square and the two element chain is again the square
+        let chain = FormalContext::<String>::from(b"B\n\n1\n1\n\nc\nz\n.\n").unwrap();
+        assert_eq!(
+            diagonal
+                .direct_product(&chain)
+                .fcbo_index_concepts()
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn object_generating_set() {
+        // The first object of every join-irreducible object concept, never the appended copy of object 0
+        let fixtures = [
+            ("test_data/triangles.cxt", vec![0, 1, 2, 3, 4, 5, 6]),
+            (
+                "test_data/living_beings_and_water.cxt",
+                vec![0, 1, 2, 3, 4, 5, 6, 7],
+            ),
+            ("test_data/eu.cxt", vec![2, 3, 5, 6, 9, 12, 13, 16, 19, 25]),
+            ("test_data/data_from_paper.cxt", vec![0, 1, 2, 3]),
+        ];
+        for (path, expected) in fixtures {
+            let mut context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            context.add_object(
+                "copy".to_string(),
+                &context.atomic_object_derivations[0].clone(),
+            );
+            let objects = context.object_generating_set();
+            let subcontext = context.subcontext(&objects, &context.all_attributes().clone());
+
+            assert_eq!(objects, bitset_from_indices(&expected), "{path}");
+            assert_eq!(
+                subcontext.fcbo_index_concepts().count(),
+                context.fcbo_index_concepts().count(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn attribute_implication_graph() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let edges = context.attribute_implication_graph();
+
+        assert_eq!(edges, vec![(0, 1), (0, 2)]);
+        for (m, n) in edges {
+            assert!(
+                context.implication_holds(&bitset_from_indices(&[m]), &bitset_from_indices(&[n]))
+            );
+        }
+    }
+
+    #[test]
+    fn attribute_order_matrix() {
+        // Triangles: only attribute 0 implies other attributes
+        // Living beings and water: every attribute implies needing water
+        let fixtures = [
+            (
+                "test_data/triangles.cxt",
+                vec![vec![0, 1, 2], vec![1], vec![2], vec![3], vec![4]],
+            ),
+            (
+                "test_data/living_beings_and_water.cxt",
+                vec![
+                    vec![0],
+                    vec![0, 1],
+                    vec![0, 2],
+                    vec![0, 3],
+                    vec![0, 2, 3, 4],
+                    vec![0, 3, 5],
+                    vec![0, 6],
+                    vec![0, 6, 7],
+                    vec![0, 2, 6, 7, 8],
+                ],
+            ),
+            (
+                "test_data/eu.cxt",
+                vec![
+                    vec![0, 5, 6],
+                    vec![1],
+                    vec![2, 5, 6],
+                    vec![3, 5, 6],
+                    vec![2, 4, 5, 6],
+                    vec![5],
+                    vec![5, 6],
+                ],
+            ),
+            (
+                "test_data/data_from_paper.cxt",
+                vec![
+                    vec![0],
+                    vec![1],
+                    vec![2],
+                    vec![0, 2, 3, 4, 5],
+                    vec![0, 4],
+                    vec![0, 2, 3, 4, 5],
+                ],
+            ),
+        ];
+        for (path, expected) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let matrix = context.attribute_order_matrix();
+
+            assert_eq!(matrix.len(), context.attributes.len(), "{path}");
+            for (m, row) in matrix.iter().enumerate() {
+                assert!(row.contains(m), "{path}");
+                assert_eq!(
+                    *row,
+                    context.index_attribute_hull(&bitset_from_indices(&[m])),
+                    "{path}"
+                );
+            }
+            assert_eq!(
+                matrix,
+                expected
+                    .iter()
+                    .map(|row| bitset_from_indices(row))
+                    .collect::<Vec<_>>(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn separation_index() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        for concept in context.fcbo_index_concepts() {
+            let separation = context.separation_index(&concept);
+            let separated = concept
+                .0
+                .iter()
+                .all(|g| context.atomic_object_derivations[g].is_subset(&concept.1))
+                && concept
+                    .1
+                    .iter()
+                    .all(|m| context.atomic_attribute_derivations[m].is_subset(&concept.0));
+
+            assert!((0.0..=1.0).contains(&separation));
+            assert_eq!(
+                separation == 1.0,
+                separated && !concept.0.is_empty() && !concept.1.is_empty()
+            );
+        }
+
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nXX\nX.\n").unwrap();
+        // The row of a and the columns of x and y have three crosses, the concept covers two of them
+        let concept = (bitset_from_indices(&[0]), bitset_from_indices(&[0, 1]));
+        assert_eq!(context.separation_index(&concept), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn extremal_objects() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        assert_eq!(
+            context.object_concept(5),
+            (bitset_from_indices(&[3, 5]), bitset_from_indices(&[1, 2]))
+        );
+        assert_eq!(lattice.atoms().len(), 3);
+        // {1,3}, {1,4} and {0,1,2} are the maximal object intents, held by no other object
+        assert_eq!(
+            context.extremal_objects(&lattice),
+            bitset_from_indices(&[0, 1, 3])
+        );
+    }
+
+    #[test]
+    fn conjoin_attributes() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let concepts = context.fcbo_index_concepts().count();
+        context.conjoin_attributes(1, 2, "1 and 2".to_string());
+
+        assert_eq!(context.attributes[5], "1 and 2");
+        assert_eq!(
+            context.atomic_attribute_derivations[5],
+            context.atomic_attribute_derivations[1]
+                .intersection(&context.atomic_attribute_derivations[2])
+                .collect()
+        );
+        // The new attribute has the extent of the concept generated by 1 and 2, so no concept is added
+        assert_eq!(context.fcbo_index_concepts().count(), concepts);
+        assert!(context
+            .fcbo_index_concepts()
+            .all(|(_, intent)| intent.contains(5) == (intent.contains(1) && intent.contains(2))));
+
+        context.conjoin_attributes(3, 4, "3 and 4".to_string());
+        assert!(context.atomic_attribute_derivations[6].is_empty());
+    }
+
+    #[test]
+    fn disjoin_attributes() {
+        let mut context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        context.disjoin_attributes(3, 4, "3 or 4".to_string());
+
+        assert_eq!(context.attributes[5], "3 or 4");
+        assert_eq!(
+            context.atomic_attribute_derivations[5],
+            context.atomic_attribute_derivations[3]
+                .union(&context.atomic_attribute_derivations[4])
+                .collect()
+        );
+        assert!(context.implication_holds(&bitset_from_indices(&[3]), &bitset_from_indices(&[5])));
+        assert!(context.implication_holds(&bitset_from_indices(&[4]), &bitset_from_indices(&[5])));
+    }
+
+    #[test]
+    fn concept_ids() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 7, 5, 4),
+            ("test_data/living_beings_and_water.cxt", 8, 9, 2),
+            ("test_data/eu.cxt", 14, 7, 5),
+            ("test_data/data_from_paper.cxt", 4, 5, 1),
+        ];
+        for (path, object_concept_count, attribute_concept_count, shared_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let lattice = ConceptLattice::new(&context);
+            let object_ids = context.object_concept_ids(&lattice);
+            let attribute_ids = context.attribute_concept_ids(&lattice);
+
+            for (g, &id) in object_ids.iter().enumerate() {
+                assert_eq!(lattice.concepts[id], context.object_concept(g), "{path}");
+            }
+            for (m, &id) in attribute_ids.iter().enumerate() {
+                assert_eq!(lattice.concepts[id], context.attribute_concept(m), "{path}");
+            }
+
+            let object_concepts: BitSet = object_ids.iter().copied().collect();
+            let attribute_concepts: BitSet = attribute_ids.iter().copied().collect();
+            assert_eq!(object_concepts.len(), object_concept_count, "{path}");
+            assert_eq!(attribute_concepts.len(), attribute_concept_count, "{path}");
+            assert_eq!(
+                object_concepts.intersection(&attribute_concepts).count(),
+                shared_count,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn concept_ids_of_single_objects_and_attributes() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let lattice = ConceptLattice::new(&context);
+        let object_ids = context.object_concept_ids(&lattice);
+        let attribute_ids = context.attribute_concept_ids(&lattice);
+
+        // Only object 3 has attribute 0, so both generate the concept with extent {3}
+        assert_eq!(object_ids[3], attribute_ids[0]);
+        assert_eq!(
+            lattice.concepts[attribute_ids[0]].0,
+            bitset_from_indices(&[3])
+        );
+        assert_eq!(
+            lattice.concepts[attribute_ids[1]].0,
+            bitset_from_indices(&[0, 1, 3, 5])
+        );
+
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+        let lattice = ConceptLattice::new(&context);
+
+        // Every object needs water, so that attribute generates the top concept
+        assert_eq!(context.attribute_concept_ids(&lattice)[0], lattice.top());
+    }
+
+    #[test]
+    fn from_lenient() {
+        let contents = fs::read("test_data/triangles.cxt").unwrap();
+        let (context, errors) = FormalContext::<String>::from_lenient(&contents);
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            context.incidence,
+            FormalContext::<String>::from(&contents).unwrap().incidence
+        );
+
+        let (context, errors) =
+            FormalContext::<String>::from_lenient(b"B\n\n3\n2\n\na\nb\nc\nx\ny\nX.\nX?X\n.X\n");
+
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0], super::FormatError::InvalidFormat));
+        assert_eq!(context.objects.len(), 3);
+        assert!(context.atomic_object_derivations[1].is_empty());
+        assert_eq!(
+            context.atomic_object_derivations[2],
+            bitset_from_indices(&[1])
+        );
+
+        let (context, errors) = FormalContext::<String>::from_lenient(b"C\n");
+        assert_eq!(errors.len(), 1);
+        assert!(context.objects.is_empty());
+    }
+
+    #[test]
+    fn indiscernibility_classes() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+
+        assert!(context.is_clarified());
+        assert_eq!(
+            context.indiscernibility_classes(context.all_attributes()),
+            (0..context.objects.len())
+                .map(|g| bitset_from_indices(&[g]))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            context.indiscernibility_classes(&BitSet::new()),
+            vec![context.all_objects().clone()]
+        );
+        assert_eq!(
+            context.indiscernibility_classes(&bitset_from_indices(&[1, 2])),
+            vec![
+                bitset_from_indices(&[0, 1]),
+                bitset_from_indices(&[2]),
+                bitset_from_indices(&[3, 5]),
+                bitset_from_indices(&[4, 6])
+            ]
+        );
+    }
+
+    #[test]
+    fn approximations() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+
+        for m in 0..context.attributes.len() {
+            let attributes: BitSet = context
+                .all_attributes()
+                .iter()
+                .filter(|&n| n != m)
+                .collect();
+            let target = &context.atomic_attribute_derivations[m];
+            let lower = context.lower_approximation(target, &attributes);
+            let upper = context.upper_approximation(target, &attributes);
+
+            assert!(lower.is_subset(target));
+            assert!(target.is_subset(&upper));
+            assert_eq!(
+                context.lower_approximation(target, context.all_attributes()),
+                *target
+            );
+            assert_eq!(
+                context.upper_approximation(target, context.all_attributes()),
+                *target
+            );
+        }
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        // Restricted to 1 and 2, object 3 can not be told apart from 5 and object 0 not from 1
+        let target = bitset_from_indices(&[0, 3]);
+        let attributes = bitset_from_indices(&[1, 2]);
+
+        assert!(context.lower_approximation(&target, &attributes).is_empty());
+        assert_eq!(
+            context.upper_approximation(&target, &attributes),
+            bitset_from_indices(&[0, 1, 3, 5])
+        );
+    }
+
+    #[test]
+    fn scale_measure() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let identity: Vec<usize> = (0..context.objects.len()).collect();
+
+        assert!(context.is_scale_measure_of(&context, &identity));
+        assert!(!context.is_scale_measure_of(&context, &identity[1..]));
+
+        // Mapping a copy of an object to the original keeps every preimage closed
+        let mut other = context.clone();
+        other.add_object(
+            "copy".to_string(),
+            &context.atomic_object_derivations[0].clone(),
+        );
+        let mut sigma = identity.clone();
+        sigma.push(0);
+        assert!(context.is_scale_measure_of(&other, &sigma));
+
+        // The preimage of the extent {2,3,5} of 2 would be {3,5,6}, which is not an extent
+        let mut sigma = identity.clone();
+        sigma.swap(2, 6);
+        assert!(!context.is_scale_measure_of(&context, &sigma));
+    }
+
+    #[test]
+    fn analysis_report() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let report = context.analysis_report();
+
+        assert!(report.contains("Concepts: 10\n"));
+        assert!(report.contains(&format!(
+            "Canonical basis: {} implications\n",
+            context.canonical_basis().len()
+        )));
+        assert!(report.contains("Height: 5\n"));
+        assert!(report.contains("Top concept: {0, 1, 2, 3, 4, 5, 6} x {}\n"));
+        assert!(report.contains("{0} -> {0, 1, 2}\n"));
+    }
+
+    #[test]
+    fn attribute_recommendations() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/eu.cxt").unwrap()).unwrap();
+        for m in 0..context.attributes.len() {
+            let known = bitset_from_indices(&[m]);
+            let (certain, possible) = context.attribute_recommendations(&known);
+
+            assert_eq!(
+                certain,
+                context
+                    .index_attribute_hull(&known)
+                    .difference(&known)
+                    .collect()
+            );
+            assert!(certain.is_disjoint(&possible) && known.is_disjoint(&possible));
+        }
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        // Nothing follows from 1, but the objects 0, 1, 3 and 5 with 1 have every other attribute between them
+        assert_eq!(
+            context.attribute_recommendations(&bitset_from_indices(&[1])),
+            (BitSet::new(), bitset_from_indices(&[0, 2, 3, 4]))
+        );
+        assert_eq!(
+            context.attribute_recommendations(&bitset_from_indices(&[0])),
+            (bitset_from_indices(&[1, 2]), BitSet::new())
+        );
+    }
+
+    #[test]
+    fn attribute_stream_lattice() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let order = [4, 1, 3, 0, 2];
+        let stream = context.attribute_stream_lattice(&order);
+
+        assert_eq!(stream.len(), order.len());
+        // Attribute 4 alone gives its attribute concept below the top concept
+        assert_eq!(stream[0].len(), 2);
+        for (length, concepts) in stream.iter().enumerate() {
+            let prefix: BitSet = order[..=length].iter().copied().collect();
+            for (extent, intent) in concepts {
+                assert!(intent.is_subset(&prefix));
+                assert_eq!(
+                    *intent,
+                    context
+                        .index_object_derivation(extent)
+                        .intersection(&prefix)
+                        .collect()
+                );
+            }
+        }
+
+        let mut last = stream.last().unwrap().clone();
+        let mut concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+        last.sort();
+        concepts.sort();
+        assert_eq!(last, concepts);
+    }
+
+    #[test]
+    fn attribute_stream_lattice_repeated_and_unknown() {
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let expected = context.attribute_stream_lattice(&[4, 1]);
+
+        // The repeated 4 and the unknown 9 leave the prefix {4} unchanged
+        let stream = context.attribute_stream_lattice(&[4, 4, 9, 1]);
+        assert_eq!(
+            stream,
+            vec![
+                expected[0].clone(),
+                expected[0].clone(),
+                expected[0].clone(),
+                expected[1].clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn concept_weights() {
+        // The objects of triangles, living beings and water and data from paper have different intents, the 48
+        // countries of eu have 14 different rows and 15 of them share one intent
+        let fixtures = [
+            ("test_data/triangles.cxt", 10, vec![1; 7]),
+            ("test_data/living_beings_and_water.cxt", 19, vec![1; 8]),
+            (
+                "test_data/eu.cxt",
+                19,
+                vec![15, 4, 4, 4, 3, 3, 3, 2, 2, 2, 2, 2, 1, 1],
+            ),
+            ("test_data/data_from_paper.cxt", 12, vec![1; 4]),
+        ];
+        for (path, concept_count, positive_weights) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+            let weights = context.concept_weights();
+
+            assert_eq!(weights.len(), concept_count, "{path}");
+            assert_eq!(
+                weights.iter().map(|(_, weight)| weight).sum::<usize>(),
+                context.objects.len(),
+                "{path}"
+            );
+            for (concept, weight) in &weights {
+                assert_eq!(
+                    *weight,
+                    (0..context.objects.len())
+                        .filter(|&g| context.object_concept(g) == *concept)
+                        .count(),
+                    "{path}"
+                );
+            }
+
+            let mut sorted: Vec<usize> = weights
+                .iter()
+                .map(|(_, weight)| *weight)
+                .filter(|&weight| weight > 0)
+                .collect();
+            sorted.sort_by(|a, b| b.cmp(a));
+            assert_eq!(sorted, positive_weights, "{path}");
+        }
+    }
+
+    #[test]
+    fn kind() {
+        assert_eq!(FormalContext::<String>::new().kind(), ContextKind::Empty);
+        let mut context = FormalContext::<String>::new();
+        context.add_object("a".to_string(), &BitSet::new());
+        assert_eq!(context.kind(), ContextKind::Empty);
+
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\n..\n..\n").unwrap();
+        assert_eq!(context.kind(), ContextKind::NoIncidence);
+
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\na\nb\nx\ny\nXX\nXX\n").unwrap();
+        assert_eq!(context.kind(), ContextKind::Full);
+
+        let context =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        assert_eq!(context.kind(), ContextKind::General);
+    }
 }
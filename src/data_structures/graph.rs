@@ -1,6 +1,7 @@
 use bit_set::BitSet;
+use std::collections::{HashMap, HashSet};
 
-use crate::FormalContext;
+use crate::{ConceptIndex, ContextKind, FormalContext};
 
 /// Graphs are important
 pub struct Graph<T> {
@@ -18,6 +19,13 @@ pub struct Node<T> {
     pub label: (Option<Vec<T>>, Option<Vec<T>>),
 }
 
+/// Options controlling how the nodes of a graph are placed.
+#[derive(Clone, Copy, Default)]
+pub struct LayoutOptions {
+    /// Places every node on the layer given by the size of its intent instead of the computed layer.
+    pub level_by_intent: bool,
+}
+
 struct Task<'a> {
     set_index: usize,
     set: &'a BitSet,
@@ -29,6 +37,12 @@ impl<'a> PartialEq for Task<'a> {
     }
 }
 
+impl<T: Clone> Default for Graph<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: Clone> Graph<T> {
     /// Creates an empty graph.
     pub fn new() -> Self {
@@ -42,29 +56,47 @@ impl<T: Clone> Graph<T> {
 
     /// Creates a Graph from a set of concepts and their context.
     pub fn from_concepts(
-        concepts: &Vec<(BitSet, BitSet)>,
+        concepts: &[(BitSet, BitSet)],
         context: &FormalContext<T>,
     ) -> Option<Self> {
+        Self::from_concepts_with_options(concepts, context, LayoutOptions::default())
+    }
+
+    /// Creates a Graph from a set of concepts and their context, using the given layout options.
+    pub fn from_concepts_with_options(
+        concepts: &[(BitSet, BitSet)],
+        context: &FormalContext<T>,
+        options: LayoutOptions,
+    ) -> Option<Self> {
+        // An empty context has a single concept, and a graph needs at least two
+        if context.kind() == ContextKind::Empty || concepts.len() < 2 {
+            return None;
+        }
+
+        let intent_sizes: Vec<usize> = concepts.iter().map(|x| x.1.len()).collect();
+
+        // Looking up concepts by their extent is constant time instead of linear in the number of concepts,
+        // which keeps the construction from becoming quadratic in the concept count
+        let concept_indices = ConceptIndex::new(concepts);
         let concepts: Vec<BitSet> = concepts.iter().map(|x| x.0.clone()).collect();
 
         let mut edges: Vec<(u32, u32)> = Vec::new();
+        // A concept can be reached from several of its lower covers, so the emitted edges and the
+        // concepts whose covers were already walked are remembered
+        let mut emitted: HashSet<(u32, u32)> = HashSet::new();
+        let mut visited: HashSet<usize> = HashSet::new();
         let mut queue: Vec<Task> = Vec::new();
-        let mut root_index = concepts.len() - 1;
-
-        if root_index == 0 {
-            return None;
-        }
+        // The walk goes upwards from the bottom concept, which has the smallest extent
+        let mut root_index = (0..concepts.len())
+            .min_by_key(|&index| concepts[index].len())
+            .unwrap();
 
         'a: loop {
-            let lenght;
-            if queue.len() > 0 {
-                lenght = queue.len()
-            } else {
-                lenght = 1;
-            }
+            let lenght = if !queue.is_empty() { queue.len() } else { 1 };
 
             for _ in 0..lenght {
-                let obj_list = FormalContext::upper_neighbor(&context, &concepts[root_index]);
+                visited.insert(root_index);
+                let obj_list = FormalContext::upper_neighbor(context, &concepts[root_index]);
                 for n in &obj_list {
                     let mut set_n = BitSet::new();
                     set_n.insert(n);
@@ -74,19 +106,22 @@ impl<T: Clone> Graph<T> {
                         &set_n.union(&concepts[root_index]).collect(),
                     );
 
-                    let set_index = concepts.iter().position(|x| *x == concept).unwrap();
+                    let set_index = concept_indices.by_extent(&concept).unwrap();
 
                     let new_task = Task {
-                        set_index: set_index,
+                        set_index,
                         set: &concepts[set_index],
                     };
 
-                    if !queue.contains(&new_task) {
+                    let edge = (set_index as u32, root_index as u32);
+                    if emitted.insert(edge) {
+                        edges.push(edge);
+                    }
+                    if !visited.contains(&set_index) && !queue.contains(&new_task) {
                         queue.push(new_task);
-                        edges.push((set_index as u32, root_index as u32));
                     }
                 }
-                if queue.len() != 0 {
+                if !queue.is_empty() {
                     let task = queue.pop().unwrap();
                     root_index = task.set_index;
                 } else {
@@ -100,9 +135,8 @@ impl<T: Clone> Graph<T> {
         for obj in 0..context.objects.len() {
             let mut g = BitSet::new();
             g.insert(obj);
-            let index = concepts
-                .iter()
-                .position(|x| *x == context.index_object_hull(&g))
+            let index = concept_indices
+                .by_extent(&context.index_object_hull(&g))
                 .unwrap();
             obj_labels.push((index, obj));
         }
@@ -112,14 +146,13 @@ impl<T: Clone> Graph<T> {
         for attr in 0..context.attributes.len() {
             let mut m = BitSet::new();
             m.insert(attr);
-            let index = concepts
-                .iter()
-                .position(|x| *x == context.index_attribute_derivation(&m))
+            let index = concept_indices
+                .by_extent(&context.index_attribute_derivation(&m))
                 .unwrap();
             attr_labels.push((index, attr));
         }
 
-        let (points, width, height) = rust_sugiyama::from_edges(&edges)
+        let (points, mut width, mut height) = rust_sugiyama::from_edges(&edges)
             .vertex_spacing(1)
             .vertex_spacing(1)
             .build()
@@ -129,17 +162,41 @@ impl<T: Clone> Graph<T> {
             .iter()
             .map(|point| Node {
                 id: point.0,
-                x: point.1 .0.abs() as usize,
-                y: point.1 .1.abs() as usize,
+                x: point.1 .0.unsigned_abs(),
+                y: point.1 .1.unsigned_abs(),
                 label: (None, None),
             })
             .collect();
 
+        if options.level_by_intent {
+            for node in nodes.iter_mut() {
+                node.y = intent_sizes[node.id];
+            }
+            height = nodes.iter().map(|node| node.y + 1).max().unwrap_or(0);
+
+            // Nodes from different computed layers can share a level, so each level is spread out again,
+            // keeping the computed left to right order
+            let mut levels: HashMap<usize, Vec<usize>> = HashMap::new();
+            for (index, node) in nodes.iter().enumerate() {
+                levels.entry(node.y).or_default().push(index);
+            }
+            for level in levels.values_mut() {
+                level.sort_by_key(|&index| (nodes[index].x, nodes[index].id));
+                for (x, &index) in level.iter().enumerate() {
+                    nodes[index].x = x;
+                }
+            }
+            width = levels.values().map(|level| level.len()).max().unwrap_or(0);
+        }
+
+        let node_indices: HashMap<usize, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.id, index))
+            .collect();
+
         for (concept_index, obj) in obj_labels {
-            let index = nodes
-                .iter()
-                .position(|node| node.id == concept_index)
-                .unwrap();
+            let index = node_indices[&concept_index];
             if let Some(ref mut obj_vec) = nodes[index].label.0 {
                 obj_vec.push(context.objects[obj].clone());
             } else {
@@ -148,10 +205,7 @@ impl<T: Clone> Graph<T> {
         }
 
         for (concept_index, attr) in attr_labels {
-            let index = nodes
-                .iter()
-                .position(|node| node.id == concept_index)
-                .unwrap();
+            let index = node_indices[&concept_index];
             if let Some(ref mut attr_vec) = nodes[index].label.1 {
                 attr_vec.push(context.attributes[attr].clone());
             } else {
@@ -160,24 +214,64 @@ impl<T: Clone> Graph<T> {
         }
 
         let graph = Graph {
-            width: width,
-            height: height,
-            edges: edges,
-            nodes: nodes,
+            width,
+            height,
+            edges,
+            nodes,
         };
 
         Some(graph)
     }
+
+    /// Removes all duplicate edges and all edges implied by transitivity, leaving only the covering edges.
+    pub fn transitive_reduction(&mut self) {
+        let mut seen: HashSet<(u32, u32)> = HashSet::new();
+        self.edges.retain(|&edge| seen.insert(edge));
+
+        let mut successors: HashMap<u32, Vec<u32>> = HashMap::new();
+        for &(from, to) in &self.edges {
+            successors.entry(from).or_default().push(to);
+        }
+
+        let reachable = |from: u32, to: u32| -> bool {
+            let mut stack = vec![from];
+            let mut visited: HashSet<u32> = HashSet::new();
+            while let Some(node) = stack.pop() {
+                if node == to {
+                    return true;
+                }
+                if visited.insert(node) {
+                    if let Some(next) = successors.get(&node) {
+                        stack.extend(next);
+                    }
+                }
+            }
+            false
+        };
+
+        let redundant: HashSet<(u32, u32)> = self
+            .edges
+            .iter()
+            .filter(|&&(from, to)| {
+                successors[&from]
+                    .iter()
+                    .any(|&middle| middle != to && reachable(middle, to))
+            })
+            .copied()
+            .collect();
+
+        self.edges.retain(|edge| !redundant.contains(edge));
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::fs;
+    use std::{collections::HashSet, fs};
 
     use bit_set::BitSet;
 
     use crate::{
-        data_structures::graph::{Graph, Node},
+        data_structures::graph::{Graph, LayoutOptions, Node},
         FormalContext,
     };
 
@@ -189,73 +283,74 @@ mod tests {
 
         let graph = Graph::from_concepts(&concepts, &context).unwrap();
 
-        let mut nodes = Vec::new();
-        nodes.push(Node {
-            id: 0,
-            x: 1,
-            y: 0,
-            label: (None, None),
-        });
-        nodes.push(Node {
-            id: 1,
-            x: 3,
-            y: 3,
-            label: (Some(vec!["3".to_string()]), Some(vec!["0".to_string()])),
-        });
-        nodes.push(Node {
-            id: 2,
-            x: 1,
-            y: 1,
-            label: (None, Some(vec!["1".to_string()])),
-        });
-        nodes.push(Node {
-            id: 3,
-            x: 3,
-            y: 1,
-            label: (Some(vec!["2".to_string()]), Some(vec!["2".to_string()])),
-        });
-        nodes.push(Node {
-            id: 4,
-            x: 0,
-            y: 1,
-            label: (Some(vec!["4".to_string()]), Some(vec!["3".to_string()])),
-        });
-        nodes.push(Node {
-            id: 5,
-            x: 2,
-            y: 1,
-            label: (Some(vec!["6".to_string()]), Some(vec!["4".to_string()])),
-        });
-        nodes.push(Node {
-            id: 6,
-            x: 3,
-            y: 2,
-            label: (Some(vec!["5".to_string()]), None),
-        });
-        nodes.push(Node {
-            id: 7,
-            x: 0,
-            y: 2,
-            label: (Some(vec!["0".to_string()]), None),
-        });
-        nodes.push(Node {
-            id: 8,
-            x: 1,
-            y: 2,
-            label: (Some(vec!["1".to_string()]), None),
-        });
-        nodes.push(Node {
-            id: 9,
-            x: 1,
-            y: 4,
-            label: (None, None),
-        });
+        let nodes = vec![
+            Node {
+                id: 0,
+                x: 1,
+                y: 0,
+                label: (None, None),
+            },
+            Node {
+                id: 1,
+                x: 2,
+                y: 3,
+                label: (Some(vec!["3".to_string()]), Some(vec!["0".to_string()])),
+            },
+            Node {
+                id: 2,
+                x: 2,
+                y: 1,
+                label: (None, Some(vec!["1".to_string()])),
+            },
+            Node {
+                id: 3,
+                x: 3,
+                y: 1,
+                label: (Some(vec!["2".to_string()]), Some(vec!["2".to_string()])),
+            },
+            Node {
+                id: 4,
+                x: 0,
+                y: 1,
+                label: (Some(vec!["4".to_string()]), Some(vec!["3".to_string()])),
+            },
+            Node {
+                id: 5,
+                x: 1,
+                y: 1,
+                label: (Some(vec!["6".to_string()]), Some(vec!["4".to_string()])),
+            },
+            Node {
+                id: 6,
+                x: 2,
+                y: 2,
+                label: (Some(vec!["5".to_string()]), None),
+            },
+            Node {
+                id: 7,
+                x: 0,
+                y: 2,
+                label: (Some(vec!["0".to_string()]), None),
+            },
+            Node {
+                id: 8,
+                x: 1,
+                y: 2,
+                label: (Some(vec!["1".to_string()]), None),
+            },
+            Node {
+                id: 9,
+                x: 1,
+                y: 4,
+                label: (None, None),
+            },
+        ];
 
         assert!(graph.width == 4);
         assert!(graph.height == 5);
         assert!(graph.nodes.len() == nodes.len());
         for node in &graph.nodes {
-            assert!(nodes.contains(&node));
+            assert!(nodes.contains(node));
         }
 
         // println!("Width: {}", graph.width);
@@ -270,4 +365,121 @@ mod tests {
         //     }
         // }
     }
+
+    #[test]
+    fn graph_edges() {
+        let context: FormalContext<String> =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+
+        let graph = Graph::from_concepts(&concepts, &context).unwrap();
+
+        let edges: Vec<(u32, u32)> = vec![
+            (7, 9),
+            (8, 9),
+            (1, 9),
+            (6, 1),
+            (2, 6),
+            (3, 6),
+            (0, 3),
+            (0, 2),
+            (2, 8),
+            (5, 8),
+            (0, 5),
+            (4, 7),
+            (2, 7),
+            (0, 4),
+        ];
+
+        assert_eq!(graph.edges, edges);
+    }
+
+    #[test]
+    fn graph_bottom_concept_not_last() {
+        // FCbO lists the bottom concept second, before the concept of g
+        let context = FormalContext::<String>::from(b"B\n\n2\n2\n\ng\nh\na\nb\n.X\n..\n").unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+        assert_eq!(concepts[1].0, BitSet::new());
+
+        let graph = Graph::from_concepts(&concepts, &context).unwrap();
+        assert_eq!(graph.edges, vec![(2, 1), (0, 2)]);
+        assert_eq!(graph.nodes.len(), 3);
+    }
+
+    #[test]
+    fn graph_from_degenerate_input() {
+        let context: FormalContext<String> =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        assert!(Graph::from_concepts(&[], &context).is_none());
+
+        let empty = FormalContext::<String>::from(b"B\n\n0\n2\n\na\nb\n").unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = empty.fcbo_index_concepts().collect();
+        assert_eq!(concepts.len(), 1);
+        assert!(Graph::from_concepts(&concepts, &empty).is_none());
+    }
+
+    #[test]
+    fn graph_level_by_intent() {
+        let context: FormalContext<String> =
+            FormalContext::<String>::from(&fs::read("test_data/triangles.cxt").unwrap()).unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+
+        let options = LayoutOptions {
+            level_by_intent: true,
+        };
+        let graph = Graph::from_concepts_with_options(&concepts, &context, options).unwrap();
+
+        let top = graph.nodes.iter().find(|node| node.id == 0).unwrap();
+        let bottom = graph.nodes.iter().find(|node| node.id == 9).unwrap();
+        let max = graph.nodes.iter().map(|node| node.y).max().unwrap();
+
+        assert_eq!(top.y, 0);
+        assert_eq!(bottom.y, max);
+        assert_eq!(bottom.y, context.attributes.len());
+        assert_eq!(graph.height, max + 1);
+        for node in &graph.nodes {
+            assert_eq!(node.y, concepts[node.id].1.len());
+        }
+
+        // The levels have 1, 4, 3, 1 and 1 nodes, no two nodes share a position
+        let positions: HashSet<(usize, usize)> =
+            graph.nodes.iter().map(|node| (node.x, node.y)).collect();
+        assert_eq!(positions.len(), graph.nodes.len());
+        assert_eq!(graph.width, 4);
+        for node in &graph.nodes {
+            assert!(node.x < graph.width);
+        }
+    }
+
+    #[test]
+    fn graph_level_by_intent_spreads_levels() {
+        // The computed layout puts two of the concepts with intents of size 2 at the same x
+        let context = FormalContext::<String>::from(
+            b"B\n\n4\n4\n\ng\nh\ni\nj\na\nb\nc\nd\n....\n.XXX\nX..X\nXXX.\n",
+        )
+        .unwrap();
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+
+        let options = LayoutOptions {
+            level_by_intent: true,
+        };
+        let graph = Graph::from_concepts_with_options(&concepts, &context, options).unwrap();
+
+        let positions: HashSet<(usize, usize)> =
+            graph.nodes.iter().map(|node| (node.x, node.y)).collect();
+        assert_eq!(positions.len(), 8);
+        for node in &graph.nodes {
+            assert!(node.x < graph.width);
+        }
+    }
+
+    #[test]
+    fn graph_transitive_reduction() {
+        let mut graph: Graph<String> = Graph::new();
+        graph.edges = vec![(0, 1), (1, 2), (0, 2), (0, 1)];
+
+        graph.transitive_reduction();
+
+        assert_eq!(graph.edges, vec![(0, 1), (1, 2)]);
+    }
 }
@@ -0,0 +1,130 @@
+use bit_set::BitSet;
+use std::ops::{Deref, DerefMut};
+
+use crate::FormalContext;
+
+/// A set of object indices, used to keep object and attribute sets apart.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Extent(pub BitSet);
+
+/// A set of attribute indices, used to keep object and attribute sets apart.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Intent(pub BitSet);
+
+impl Deref for Extent {
+    type Target = BitSet;
+
+    fn deref(&self) -> &BitSet {
+        &self.0
+    }
+}
+
+impl DerefMut for Extent {
+    fn deref_mut(&mut self) -> &mut BitSet {
+        &mut self.0
+    }
+}
+
+impl From<BitSet> for Extent {
+    fn from(set: BitSet) -> Self {
+        Extent(set)
+    }
+}
+
+impl From<Extent> for BitSet {
+    fn from(extent: Extent) -> Self {
+        extent.0
+    }
+}
+
+impl Deref for Intent {
+    type Target = BitSet;
+
+    fn deref(&self) -> &BitSet {
+        &self.0
+    }
+}
+
+impl DerefMut for Intent {
+    fn deref_mut(&mut self) -> &mut BitSet {
+        &mut self.0
+    }
+}
+
+impl From<BitSet> for Intent {
+    fn from(set: BitSet) -> Self {
+        Intent(set)
+    }
+}
+
+impl From<Intent> for BitSet {
+    fn from(intent: Intent) -> Self {
+        intent.0
+    }
+}
+
+/// Typed versions of the derivation and hull methods.
+///
+/// The `index_*` methods keep working on plain `BitSet`s and are not deprecated. To migrate, wrap the
+/// sets with `Extent::from` or `Intent::from`, call the method without the `index_` prefix and turn the
+/// result back with `BitSet::from` where a plain set is still needed.
+impl<T> FormalContext<T> {
+    /// Computes the object derivation of an extent, the typed version of `index_object_derivation`.
+    pub fn object_derivation(&self, extent: &Extent) -> Intent {
+        Intent(self.index_object_derivation(extent))
+    }
+
+    /// Computes the attribute derivation of an intent, the typed version of `index_attribute_derivation`.
+    pub fn attribute_derivation(&self, intent: &Intent) -> Extent {
+        Extent(self.index_attribute_derivation(intent))
+    }
+
+    /// Computes the object hull of an extent, the typed version of `index_object_hull`.
+    pub fn object_hull(&self, extent: &Extent) -> Extent {
+        Extent(self.index_object_hull(extent))
+    }
+
+    /// Computes the attribute hull of an intent, the typed version of `index_attribute_hull`.
+    pub fn attribute_hull(&self, intent: &Intent) -> Intent {
+        Intent(self.index_attribute_hull(intent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use bit_set::BitSet;
+
+    use crate::{
+        data_structures::index_sets::{Extent, Intent},
+        util::bitset_from_indices,
+        FormalContext,
+    };
+
+    #[test]
+    fn typed_derivations() {
+        let context = FormalContext::<String>::from(
+            &fs::read("test_data/living_beings_and_water.cxt").unwrap(),
+        )
+        .unwrap();
+
+        let extent = Extent::from(bitset_from_indices(&[0, 1, 2, 3]));
+        let intent = context.object_derivation(&extent);
+        assert_eq!(*intent, context.index_object_derivation(&extent));
+        assert_eq!(
+            context.attribute_derivation(&intent),
+            Extent(context.index_attribute_derivation(&intent))
+        );
+
+        let intent = Intent::from(bitset_from_indices(&[1]));
+        assert_eq!(
+            BitSet::from(context.attribute_hull(&intent)),
+            context.index_attribute_hull(&intent)
+        );
+        assert_eq!(
+            BitSet::from(context.object_hull(&extent)),
+            context.index_object_hull(&extent)
+        );
+    }
+}
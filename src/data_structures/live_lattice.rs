@@ -0,0 +1,112 @@
+use bit_set::BitSet;
+use std::collections::HashMap;
+
+use crate::FormalContext;
+
+/// A formal context together with its concepts, which are updated incrementally when objects are added.
+pub struct LiveLattice<T> {
+    context: FormalContext<T>,
+    concepts: Vec<(BitSet, BitSet)>,
+    intents: HashMap<BitSet, usize>,
+}
+
+impl<T> LiveLattice<T> {
+    /// Creates a LiveLattice from a context, computing its concepts once.
+    pub fn new(context: FormalContext<T>) -> Self {
+        let concepts: Vec<(BitSet, BitSet)> = context.fcbo_index_concepts().collect();
+        let intents = concepts
+            .iter()
+            .enumerate()
+            .map(|(index, (_, intent))| (intent.clone(), index))
+            .collect();
+
+        LiveLattice {
+            context,
+            concepts,
+            intents,
+        }
+    }
+
+    /// Returns the underlying context.
+    pub fn context(&self) -> &FormalContext<T> {
+        &self.context
+    }
+
+    /// Returns the concepts of the current context.
+    pub fn concepts(&self) -> &[(BitSet, BitSet)] {
+        &self.concepts
+    }
+
+    /// Adds a new object with its corresponding attributes and updates the concepts without recomputing them.
+    pub fn add_object(&mut self, new_object: T, attributes: &BitSet) {
+        self.context.add_object(new_object, attributes);
+        let object_index = self.context.objects.len() - 1;
+
+        // Every new intent is the intersection of an old intent with the new object intent
+        // Its extent is the one of the smallest old intent generating it, together with the new object
+        let mut generators: HashMap<BitSet, BitSet> = HashMap::new();
+        for (extent, intent) in self.concepts.iter_mut() {
+            if intent.is_subset(attributes) {
+                extent.insert(object_index);
+                continue;
+            }
+
+            let new_intent: BitSet = intent.intersection(attributes).collect();
+            if self.intents.contains_key(&new_intent) {
+                continue;
+            }
+            let generator = generators.entry(new_intent).or_default();
+            if extent.len() > generator.len() {
+                *generator = extent.clone();
+            }
+        }
+
+        for (new_intent, mut extent) in generators {
+            extent.insert(object_index);
+            self.intents.insert(new_intent.clone(), self.concepts.len());
+            self.concepts.push((extent, new_intent));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeSet, fs};
+
+    use bit_set::BitSet;
+
+    use crate::{data_structures::live_lattice::LiveLattice, FormalContext};
+
+    #[test]
+    fn live_lattice_add_objects() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+            ("test_data/data_from_paper.cxt", 12),
+        ];
+        for (path, concept_count) in fixtures {
+            let context = FormalContext::<String>::from(&fs::read(path).unwrap()).unwrap();
+
+            let mut empty = FormalContext::<String>::new();
+            for attribute in &context.attributes {
+                empty.add_attribute(attribute.clone(), &BitSet::new());
+            }
+
+            let mut lattice = LiveLattice::new(empty);
+            for (object, intent) in context
+                .objects
+                .iter()
+                .zip(context.atomic_object_derivations.iter())
+            {
+                lattice.add_object(object.clone(), intent);
+            }
+
+            let concepts: BTreeSet<_> = lattice.concepts().iter().cloned().collect();
+            let concepts_val: BTreeSet<_> = context.fcbo_index_concepts().collect();
+            assert_eq!(lattice.concepts().len(), concept_count, "{path}");
+            assert_eq!(concepts.len(), concept_count, "{path}");
+            assert_eq!(concepts, concepts_val, "{path}");
+        }
+    }
+}
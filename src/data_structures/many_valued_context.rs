@@ -0,0 +1,26 @@
+/// A table assigning every object a value for every attribute.
+pub struct ManyValuedContext<T, V> {
+    pub objects: Vec<T>,
+    pub attributes: Vec<T>,
+    /// The values of the objects, one row per object with one value per attribute.
+    pub values: Vec<Vec<V>>,
+}
+
+impl<T, V> ManyValuedContext<T, V> {
+    /// Creates a many-valued context from its objects, attributes and the rows of values of the objects.
+    pub fn new(objects: Vec<T>, attributes: Vec<T>, values: Vec<Vec<V>>) -> Self {
+        assert_eq!(objects.len(), values.len());
+        assert!(values.iter().all(|row| row.len() == attributes.len()));
+
+        ManyValuedContext {
+            objects,
+            attributes,
+            values,
+        }
+    }
+
+    /// Returns the value of the object for the attribute.
+    pub fn value(&self, object: usize, attribute: usize) -> &V {
+        &self.values[object][attribute]
+    }
+}
@@ -1,2 +1,9 @@
+pub(crate) mod concept_index;
+pub(crate) mod concept_lattice;
+pub(crate) mod context_diff;
 pub(crate) mod formal_context;
 pub(crate) mod graph;
+pub(crate) mod index_sets;
+pub(crate) mod live_lattice;
+pub(crate) mod many_valued_context;
+pub(crate) mod partial_context;
@@ -0,0 +1,87 @@
+use bit_set::BitSet;
+use std::io::BufRead;
+
+use crate::{data_structures::formal_context::read_burmeister_crosses, FormalContext, FormatError};
+
+/// A formal context in which some incidences are unknown. The certain context has only the known crosses,
+/// the possible context has every cross which is known or unknown.
+pub struct PartialContext<T> {
+    pub certain: FormalContext<T>,
+    pub possible: FormalContext<T>,
+}
+
+impl<T> PartialContext<T> {
+    /// Computes the concepts of the context containing only the known crosses.
+    pub fn certain_concepts(&self) -> Vec<(BitSet, BitSet)> {
+        self.certain.fcbo_index_concepts().collect()
+    }
+
+    /// Computes the concepts of the context in which every unknown incidence is a cross.
+    pub fn possible_concepts(&self) -> Vec<(BitSet, BitSet)> {
+        self.possible.fcbo_index_concepts().collect()
+    }
+
+    /// Returns the object and attribute pairs whose incidence is unknown.
+    pub fn unknown(&self) -> Vec<(usize, usize)> {
+        self.possible
+            .incidence
+            .iter()
+            .filter(|pair| !self.certain.incidence.contains(pair))
+            .copied()
+            .collect()
+    }
+}
+
+impl PartialContext<String> {
+    /// Reads a partial context in Burmeister format, where a ? marks an unknown incidence.
+    pub fn from(contents: &[u8]) -> Result<PartialContext<String>, FormatError> {
+        let certain =
+            read_burmeister_crosses(&mut contents.lines(), &|name| name.to_string(), &|x| {
+                x == 'X' || x == 'x'
+            })?;
+        let possible =
+            read_burmeister_crosses(&mut contents.lines(), &|name| name.to_string(), &|x| {
+                x == 'X' || x == 'x' || x == '?'
+            })?;
+        Ok(PartialContext { certain, possible })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::{data_structures::partial_context::PartialContext, FormalContext};
+
+    #[test]
+    fn read_partial_context() {
+        let context = PartialContext::from(b"B\n\n2\n2\n\na\nb\nx\ny\nX?\n.X\n").unwrap();
+
+        assert_eq!(context.unknown(), vec![(0, 1)]);
+        assert_eq!(context.certain.incidence.len(), 2);
+        assert_eq!(context.possible.incidence.len(), 3);
+        assert_eq!(context.certain_concepts().len(), 4);
+        assert_eq!(context.possible_concepts().len(), 2);
+    }
+
+    #[test]
+    fn bounds_without_unknowns() {
+        let fixtures = [
+            ("test_data/triangles.cxt", 10),
+            ("test_data/living_beings_and_water.cxt", 19),
+            ("test_data/eu.cxt", 19),
+            ("test_data/data_from_paper.cxt", 12),
+        ];
+        for (path, concept_count) in fixtures {
+            let contents = fs::read(path).unwrap();
+            let partial = PartialContext::from(&contents).unwrap();
+            let context = FormalContext::<String>::from(&contents).unwrap();
+            let concepts: Vec<_> = context.fcbo_index_concepts().collect();
+
+            assert!(partial.unknown().is_empty(), "{path}");
+            assert_eq!(partial.certain_concepts().len(), concept_count, "{path}");
+            assert_eq!(partial.certain_concepts(), concepts, "{path}");
+            assert_eq!(partial.possible_concepts(), concepts, "{path}");
+        }
+    }
+}
@@ -1,5 +1,15 @@
 pub mod algorithms;
 mod data_structures;
+pub mod util;
 
-pub use data_structures::formal_context::FormalContext;
-pub use data_structures::graph::Graph;
+pub use data_structures::concept_index::ConceptIndex;
+pub use data_structures::concept_lattice::ConceptLattice;
+pub use data_structures::context_diff::ContextDiff;
+pub use data_structures::formal_context::{
+    sort_canonical, ContextKind, FormalContext, FormatError,
+};
+pub use data_structures::graph::{Graph, LayoutOptions};
+pub use data_structures::index_sets::{Extent, Intent};
+pub use data_structures::live_lattice::LiveLattice;
+pub use data_structures::many_valued_context::ManyValuedContext;
+pub use data_structures::partial_context::PartialContext;
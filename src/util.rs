@@ -0,0 +1,46 @@
+use bit_set::BitSet;
+
+/// Creates a BitSet containing the given indices.
+///
+/// ```
+/// use odis::util::bitset_from_indices;
+///
+/// let set = bitset_from_indices(&[0, 3, 5]);
+/// assert!(set.contains(3));
+/// assert_eq!(set.len(), 3);
+/// ```
+pub fn bitset_from_indices(indices: &[usize]) -> BitSet {
+    indices.iter().copied().collect()
+}
+
+/// Returns the indices contained in a BitSet in ascending order.
+///
+/// ```
+/// use odis::util::{bitset_from_indices, bitset_to_indices};
+///
+/// let set = bitset_from_indices(&[5, 0, 3]);
+/// assert_eq!(bitset_to_indices(&set), vec![0, 3, 5]);
+/// ```
+pub fn bitset_to_indices(set: &BitSet) -> Vec<usize> {
+    set.iter().collect()
+}
+
+/// Returns the set with index i from sets packed into words of 64 bits, stride words per set.
+///
+/// ```
+/// use odis::util::unpack_intent;
+///
+/// let packed = [0b101, 0, 0b10, 1];
+/// assert_eq!(unpack_intent(&packed, 1, 2).iter().collect::<Vec<_>>(), vec![1, 64]);
+/// ```
+pub fn unpack_intent(packed: &[u64], i: usize, stride: usize) -> BitSet {
+    let mut set = BitSet::new();
+    for (word_index, word) in packed[i * stride..(i + 1) * stride].iter().enumerate() {
+        for bit in 0..64 {
+            if word & (1 << bit) != 0 {
+                set.insert(word_index * 64 + bit);
+            }
+        }
+    }
+    set
+}